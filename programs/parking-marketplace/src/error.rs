@@ -55,4 +55,331 @@ pub enum MarketplaceError {
 
     #[msg("Revenue share percentage exceeds 100%")]
     InvalidRevenueSharePercentage,
+
+    #[msg("Invalid distribution status for this operation")]
+    InvalidDistributionStatus,
+
+    #[msg("Distributed amount exceeds distributable revenue")]
+    DistributionOverpaid,
+
+    #[msg("Distribution has unpaid claims and the claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Invalid failure reason")]
+    InvalidFailureReason,
+
+    #[msg("Revenue period must start where the previous period ended")]
+    RevenuePeriodNotContiguous,
+
+    #[msg("Unauthorized protocol admin")]
+    UnauthorizedAdmin,
+
+    #[msg("Payout mint not accepted by the protocol")]
+    PayoutMintNotAccepted,
+
+    #[msg("Too many payout mints")]
+    TooManyPayoutMints,
+
+    #[msg("Claim window has closed")]
+    ClaimWindowClosed,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Claim window is outside the allowed range")]
+    InvalidClaimWindow,
+
+    #[msg("Invalid sweep destination")]
+    InvalidSweepDestination,
+
+    #[msg("Too many revenue oracles")]
+    TooManyRevenueOracles,
+
+    #[msg("Revenue oracle is not registered")]
+    RevenueOracleNotRegistered,
+
+    #[msg("Missing signed attestation")]
+    MissingSignedAttestation,
+
+    #[msg("Invalid signed attestation")]
+    InvalidSignedAttestation,
+
+    #[msg("Invalid operating cost caps")]
+    InvalidCostCaps,
+
+    #[msg("Operating cost exceeds its cap")]
+    OperatingCostCapExceeded,
+
+    #[msg("Revenue period ends before the latest vault deposit")]
+    RevenuePeriodExcludesInflows,
+
+    #[msg("Invalid reservation period")]
+    InvalidReservationPeriod,
+
+    #[msg("Parking spot is already booked for this period")]
+    SpotUnavailable,
+
+    #[msg("Spot calendar is full")]
+    CalendarFull,
+
+    #[msg("Price exceeds the maximum accepted by the buyer")]
+    PriceExceedsMaximum,
+
+    #[msg("Invalid pricing schedule")]
+    InvalidPricingSchedule,
+
+    #[msg("Session does not match the provided accounts")]
+    InvalidSession,
+
+    #[msg("Session is not active")]
+    SessionNotActive,
+
+    #[msg("Invalid holder privilege")]
+    InvalidHolderPrivilege,
+
+    #[msg("Invalid charging station parameters")]
+    InvalidStationParameters,
+
+    #[msg("Connector is unavailable")]
+    ConnectorUnavailable,
+
+    #[msg("Reported energy exceeds what the station can deliver")]
+    InvalidEnergyReading,
+
+    #[msg("Charger device is not registered for this station")]
+    ChargerDeviceNotRegistered,
+
+    #[msg("Charger device is already registered")]
+    ChargerDeviceAlreadyRegistered,
+
+    #[msg("Too many charger devices")]
+    TooManyChargerDevices,
+
+    #[msg("Meter reading is stale or out of order")]
+    StaleMeterReading,
+
+    #[msg("Invalid subscription")]
+    InvalidSubscription,
+
+    #[msg("Invalid subscription period")]
+    InvalidSubscriptionPeriod,
+
+    #[msg("Listing kind does not match this instruction")]
+    InvalidListingKind,
+
+    #[msg("Reservation cannot be resold")]
+    ReservationNotResellable,
+
+    #[msg("Resale price exceeds the operator's cap")]
+    ResalePriceCapExceeded,
+
+    #[msg("Invalid resale policy")]
+    InvalidResalePolicy,
+
+    #[msg("Too many bundle components")]
+    TooManyBundleComponents,
+
+    #[msg("Bundle component is invalid or already added")]
+    InvalidBundleComponent,
+
+    #[msg("Bundle composition cannot change once tokens are issued")]
+    BundleAlreadyIssued,
+
+    #[msg("Bundle component accounts missing or out of order")]
+    InvalidBundleAccounts,
+
+    #[msg("Too many index positions")]
+    TooManyIndexPositions,
+
+    #[msg("Index weights or bands are invalid")]
+    InvalidIndexWeights,
+
+    #[msg("Index position accounts missing or out of order")]
+    InvalidIndexAccounts,
+
+    #[msg("Index composition cannot change once tokens are issued")]
+    IndexAlreadyIssued,
+
+    #[msg("Rebalance interval has not elapsed")]
+    RebalanceTooSoon,
+
+    #[msg("Rebalance must move weights toward target and stay within bands")]
+    RebalanceOutsideBands,
+
+    #[msg("Buyback is closed or exhausted")]
+    BuybackClosed,
+
+    #[msg("Buyback is still open")]
+    BuybackStillOpen,
+
+    #[msg("Invalid buyback parameters")]
+    InvalidBuyback,
+
+    #[msg("Issuance would exceed the asset's max supply")]
+    SupplyCapExceeded,
+
+    #[msg("Invalid issuance parameters")]
+    InvalidIssuance,
+
+    #[msg("Issuance is not open for this kind of subscription")]
+    IssuanceNotOpen,
+
+    #[msg("Amount exceeds the holder's pre-emption allocation")]
+    PreemptionAllocationExceeded,
+
+    #[msg("Invalid operator transfer")]
+    InvalidOperatorTransfer,
+
+    #[msg("Operator transfer awaits compliance approval")]
+    OperatorTransferNotApproved,
+
+    #[msg("Unauthorized compliance authority")]
+    UnauthorizedComplianceAuthority,
+
+    #[msg("Invalid multisig signers or threshold")]
+    InvalidMultisigConfig,
+
+    #[msg("Not a registered multisig signer")]
+    NotMultisigSigner,
+
+    #[msg("Signer has already approved this action")]
+    ActionAlreadyApproved,
+
+    #[msg("Action has expired")]
+    ActionExpired,
+
+    #[msg("Action has not expired")]
+    ActionNotExpired,
+
+    #[msg("Not enough approvals to execute the action")]
+    ApprovalThresholdNotMet,
+
+    #[msg("Invalid pending action")]
+    InvalidPendingAction,
+
+    #[msg("This change must be scheduled through the timelock")]
+    ChangeRequiresTimelock,
+
+    #[msg("Timelock delay has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Invalid timelocked change")]
+    InvalidTimelockedChange,
+
+    #[msg("Timelock delay is outside the allowed range")]
+    InvalidTimelockDelay,
+
+    #[msg("Unauthorized guardian")]
+    UnauthorizedGuardian,
+
+    #[msg("Trading is paused")]
+    TradingPaused,
+
+    #[msg("Distributions are paused")]
+    DistributionsPaused,
+
+    #[msg("Issuance is paused")]
+    IssuancePaused,
+
+    #[msg("Invalid decommission parameters")]
+    InvalidDecommission,
+
+    #[msg("Final redemption window has closed")]
+    RedemptionWindowClosed,
+
+    #[msg("Final redemption window is still open")]
+    RedemptionWindowOpen,
+
+    #[msg("Invalid tender offer parameters")]
+    InvalidTenderOffer,
+
+    #[msg("Tender offer is not open")]
+    TenderOfferNotOpen,
+
+    #[msg("Tender offer acceptance threshold not met")]
+    TenderThresholdNotMet,
+
+    #[msg("Tender offer has not settled")]
+    TenderOfferNotSettled,
+
+    #[msg("Tender offer has not failed")]
+    TenderOfferNotFailed,
+
+    #[msg("Balance snapshot root is missing")]
+    MissingSnapshotRoot,
+
+    #[msg("Balance snapshot proof is invalid")]
+    InvalidSnapshotProof,
+
+    #[msg("Driver holds the maximum number of bookings for this spot")]
+    DriverBookingLimitReached,
+
+    #[msg("Reservation cannot be cancelled")]
+    ReservationNotCancellable,
+
+    #[msg("Reservation cannot be completed yet")]
+    ReservationNotCompletable,
+
+    #[msg("Refund exceeds undistributed revenue")]
+    RefundUnavailable,
+
+    #[msg("Holder token account recorded at session start is required")]
+    HolderAccountRequired,
+
+    #[msg("Session has not timed out yet")]
+    SessionNotExpired,
+
+    #[msg("Index has not been seeded yet")]
+    IndexNotSeeded,
+
+    #[msg("Index has already been seeded")]
+    IndexAlreadySeeded,
+
+    #[msg("Seed deposit is below the minimum value")]
+    IndexSeedTooSmall,
+
+    #[msg("Sale proceeds are already finalized")]
+    ProceedsFinalized,
+
+    #[msg("Sale proceeds have not been finalized")]
+    ProceedsNotFinalized,
+
+    #[msg("Tender offer has not expired yet")]
+    TenderOfferNotExpired,
+
+    #[msg("Bidder cannot tender into its own offer")]
+    BidderCannotTender,
+
+    #[msg("Distribution vault holds less than its recorded balance")]
+    DistributionVaultShortfall,
+
+    #[msg("Distributions require a revenue oracle to attest the report")]
+    RevenueAttestationRequired,
+
+    #[msg("Connector does not exist on this station")]
+    InvalidConnector,
+
+    #[msg("Charger device does not meter this session's connector")]
+    ChargerDeviceConnectorMismatch,
+
+    #[msg("Subscription pass has reached its booking limit on this spot")]
+    PassBookingLimitReached,
+
+    #[msg("Tender price values the asset below its estimated value")]
+    TenderPriceBelowValuation,
+
+    #[msg("Single-spot sessions require the spot calendar")]
+    SpotCalendarRequired,
+
+    #[msg("Supply cap must be at least the initial supply")]
+    InvalidSupplyCap,
+
+    #[msg("Pre-emption windows require a revenue oracle to attest the holder snapshot")]
+    SnapshotAttestationRequired,
+
+    #[msg("Index already collects revenue in the maximum number of mints")]
+    TooManyIndexRevenueVaults,
+
+    #[msg("Change was scheduled by a previous operator")]
+    ChangeScheduledByPreviousOperator,
 }
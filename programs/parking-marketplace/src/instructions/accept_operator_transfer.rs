@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct AcceptOperatorTransfer<'info> {
+    /// Operator transfer (closed once every asset has moved, rent returned
+    /// to the current operator)
+    #[account(
+        mut,
+        seeds = [
+            b"operator_transfer",
+            operator_transfer.parking_lot_id.to_le_bytes().as_ref(),
+            operator_transfer.current_operator.as_ref()
+        ],
+        bump = operator_transfer.bump,
+        constraint = operator_transfer.proposed_operator == new_operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = operator_transfer.is_acceptable() @ MarketplaceError::OperatorTransferNotApproved,
+    )]
+    pub operator_transfer: Account<'info, OperatorTransfer>,
+
+    /// CHECK: Current operator, receives the transfer account's rent
+    #[account(
+        mut,
+        address = operator_transfer.current_operator,
+    )]
+    pub current_operator: UncheckedAccount<'info>,
+
+    /// Operator taking over the lot
+    pub new_operator: Signer<'info>,
+    // remaining_accounts: ParkingAssets (mut) of the lot still held by the
+    // current operator
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AcceptOperatorTransfer<'info>>,
+) -> Result<()> {
+    let transfer = &mut ctx.accounts.operator_transfer;
+
+    require!(
+        !ctx.remaining_accounts.is_empty(),
+        MarketplaceError::InvalidOperatorTransfer
+    );
+
+    // Assets may move in batches; the proposal stays open until the whole
+    // lot has moved so the new operator cannot take only part of it
+    let batch_size = u32::try_from(ctx.remaining_accounts.len())
+        .map_err(|_| MarketplaceError::InvalidOperatorTransfer)?;
+    transfer.assets_transferred = transfer
+        .assets_transferred
+        .checked_add(batch_size)
+        .filter(|transferred| *transferred <= transfer.asset_count)
+        .ok_or(MarketplaceError::InvalidOperatorTransfer)?;
+
+    for asset_info in ctx.remaining_accounts.iter() {
+        let mut asset = Account::<ParkingAsset>::try_from(asset_info)?;
+        require!(
+            asset.parking_lot_id == transfer.parking_lot_id
+                && asset.institutional_operator == transfer.current_operator,
+            MarketplaceError::InvalidOperatorTransfer
+        );
+
+        asset.institutional_operator = transfer.proposed_operator;
+        asset.exit(ctx.program_id)?;
+    }
+
+    msg!(
+        "Operator transfer accepted for lot {}: {} of {} assets now managed by {}",
+        transfer.parking_lot_id,
+        transfer.assets_transferred,
+        transfer.asset_count,
+        transfer.proposed_operator
+    );
+
+    if transfer.is_complete() {
+        let current_operator = ctx.accounts.current_operator.to_account_info();
+        ctx.accounts.operator_transfer.close(current_operator)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct AddBundleComponent<'info> {
+    /// Bundle composition
+    #[account(
+        mut,
+        seeds = [b"asset_bundle", bundle_asset.key().as_ref()],
+        bump = asset_bundle.bump,
+    )]
+    pub asset_bundle: Account<'info, AssetBundle>,
+
+    /// Bundle asset
+    #[account(
+        constraint = bundle_asset.institutional_operator == creator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = bundle_asset.total_supply == 0 @ MarketplaceError::BundleAlreadyIssued,
+    )]
+    pub bundle_asset: Account<'info, ParkingAsset>,
+
+    /// Constituent asset (must be a single spot)
+    #[account(
+        constraint = component_asset.asset_type == AssetType::SingleSpot @ MarketplaceError::InvalidAssetType,
+        constraint = component_asset.asset_token_mint == component_mint.key() @ MarketplaceError::InvalidBundleComponent,
+    )]
+    pub component_asset: Account<'info, ParkingAsset>,
+
+    /// Constituent asset token mint
+    pub component_mint: Account<'info, Mint>,
+
+    /// Vault holding the constituent tokens
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"bundle_vault",
+            asset_bundle.key().as_ref(),
+            component_mint.key().as_ref()
+        ],
+        bump,
+        token::mint = component_mint,
+        token::authority = bundle_asset,
+    )]
+    pub component_vault: Account<'info, TokenAccount>,
+
+    /// Bundle creator
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<AddBundleComponent>,
+    units_per_bundle: u64,
+) -> Result<()> {
+    require!(units_per_bundle > 0, MarketplaceError::InvalidTokenAmount);
+
+    let bundle = &mut ctx.accounts.asset_bundle;
+    let component_key = ctx.accounts.component_asset.key();
+
+    require!(
+        bundle.find_component(&component_key).is_none(),
+        MarketplaceError::InvalidBundleComponent
+    );
+    require!(
+        bundle.components.len() < AssetBundle::MAX_COMPONENTS,
+        MarketplaceError::TooManyBundleComponents
+    );
+
+    bundle.components.push(BundleComponent {
+        asset_account: component_key,
+        asset_token_mint: ctx.accounts.component_mint.key(),
+        units_per_bundle,
+        vault: ctx.accounts.component_vault.key(),
+    });
+
+    msg!(
+        "Bundle component added: {} x {} per bundle token",
+        component_key,
+        units_per_bundle
+    );
+
+    Ok(())
+}
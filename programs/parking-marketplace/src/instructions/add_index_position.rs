@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct AddIndexPosition<'info> {
+    /// Index fund
+    #[account(
+        mut,
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+        constraint = index_fund.manager == manager.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Index token mint
+    #[account(
+        address = index_fund.index_mint,
+        constraint = index_mint.supply == 0 @ MarketplaceError::IndexAlreadyIssued,
+    )]
+    pub index_mint: Account<'info, Mint>,
+
+    /// Parking asset added to the index
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.asset_token_mint == asset_mint.key() @ MarketplaceError::InvalidIndexAccounts,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset token mint
+    pub asset_mint: Account<'info, Mint>,
+
+    /// Vault holding the index's asset tokens
+    #[account(
+        init,
+        payer = manager,
+        seeds = [
+            b"index_vault",
+            index_fund.key().as_ref(),
+            asset_mint.key().as_ref()
+        ],
+        bump,
+        token::mint = asset_mint,
+        token::authority = index_fund,
+    )]
+    pub position_vault: Account<'info, TokenAccount>,
+
+    /// Index manager
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<AddIndexPosition>,
+    target_weight_bps: u16,
+) -> Result<()> {
+    let index_fund = &mut ctx.accounts.index_fund;
+
+    require!(
+        index_fund.positions.len() < IndexFund::MAX_POSITIONS,
+        MarketplaceError::TooManyIndexPositions
+    );
+    require!(
+        target_weight_bps > 0
+            && index_fund.total_weight_bps() + target_weight_bps as u32 <= 10000,
+        MarketplaceError::InvalidIndexWeights
+    );
+
+    index_fund.positions.push(IndexPosition {
+        asset_account: ctx.accounts.asset_account.key(),
+        asset_token_mint: ctx.accounts.asset_mint.key(),
+        target_weight_bps,
+        vault: ctx.accounts.position_vault.key(),
+    });
+
+    msg!(
+        "Index position added: {} at {} bps",
+        ctx.accounts.asset_account.key(),
+        target_weight_bps
+    );
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct AddIndexRevenueVault<'info> {
+    /// Index fund
+    #[account(
+        mut,
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+        constraint = index_fund.manager == manager.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Payout mint the vault collects
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payout_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payout_mint: Account<'info, Mint>,
+
+    /// Vault collecting the positions' revenue in the payout mint
+    #[account(
+        init,
+        payer = manager,
+        seeds = [
+            b"index_revenue",
+            index_fund.key().as_ref(),
+            payout_mint.key().as_ref()
+        ],
+        bump,
+        token::mint = payout_mint,
+        token::authority = index_fund,
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    /// Protocol config (payout mint allowlist)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Index manager
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<AddIndexRevenueVault>,
+) -> Result<()> {
+    let index_fund = &mut ctx.accounts.index_fund;
+
+    require!(
+        index_fund.revenue_vaults.len() < IndexFund::MAX_REVENUE_VAULTS,
+        MarketplaceError::TooManyIndexRevenueVaults
+    );
+
+    // The vault joins the basket empty, so existing holders keep their share
+    index_fund.revenue_vaults.push(ctx.accounts.revenue_vault.key());
+
+    msg!(
+        "Index revenue vault added for mint {}",
+        ctx.accounts.payout_mint.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    /// Pending action
+    #[account(
+        mut,
+        seeds = [
+            b"pending_action",
+            operator_multisig.key().as_ref(),
+            pending_action.index.to_le_bytes().as_ref()
+        ],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Operator multisig
+    #[account(
+        seeds = [b"operator_multisig", operator_multisig.create_key.as_ref()],
+        bump = operator_multisig.bump,
+        constraint = operator_multisig.is_signer(&approver.key()) @ MarketplaceError::NotMultisigSigner,
+    )]
+    pub operator_multisig: Account<'info, OperatorMultisig>,
+
+    /// Multisig signer approving the action
+    pub approver: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ApproveAction>,
+) -> Result<()> {
+    let action = &mut ctx.accounts.pending_action;
+    let approver = ctx.accounts.approver.key();
+    let clock = Clock::get()?;
+
+    require!(
+        !action.is_expired(clock.unix_timestamp),
+        MarketplaceError::ActionExpired
+    );
+    require!(
+        !action.approvals.contains(&approver),
+        MarketplaceError::ActionAlreadyApproved
+    );
+    require!(
+        action.approvals.len() < OperatorMultisig::MAX_SIGNERS,
+        MarketplaceError::InvalidPendingAction
+    );
+
+    action.approvals.push(approver);
+
+    msg!(
+        "Action {} approved by {} ({} approvals)",
+        action.index,
+        approver,
+        action.approvals.len()
+    );
+
+    Ok(())
+}
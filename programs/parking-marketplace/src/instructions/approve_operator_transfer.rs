@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ApproveOperatorTransfer<'info> {
+    /// Operator transfer awaiting approval
+    #[account(
+        mut,
+        seeds = [
+            b"operator_transfer",
+            operator_transfer.parking_lot_id.to_le_bytes().as_ref(),
+            operator_transfer.current_operator.as_ref()
+        ],
+        bump = operator_transfer.bump,
+    )]
+    pub operator_transfer: Account<'info, OperatorTransfer>,
+
+    /// Protocol config (compliance authority)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.compliance_authority == compliance_authority.key() @ MarketplaceError::UnauthorizedComplianceAuthority,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Compliance authority
+    pub compliance_authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ApproveOperatorTransfer>,
+) -> Result<()> {
+    let transfer = &mut ctx.accounts.operator_transfer;
+
+    transfer.is_approved = true;
+
+    msg!(
+        "Operator transfer approved for lot {}",
+        transfer.parking_lot_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct BundleTokens<'info> {
+    /// Bundle composition
+    #[account(
+        seeds = [b"asset_bundle", bundle_asset.key().as_ref()],
+        bump = asset_bundle.bump,
+    )]
+    pub asset_bundle: Account<'info, AssetBundle>,
+
+    /// Bundle asset (mint authority for bundle tokens)
+    #[account(
+        mut,
+        constraint = bundle_asset.is_active @ MarketplaceError::AssetNotActive,
+        constraint = !bundle_asset.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub bundle_asset: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Bundle token mint, validated against the bundle asset
+    #[account(
+        mut,
+        address = bundle_asset.asset_token_mint,
+    )]
+    pub bundle_mint: UncheckedAccount<'info>,
+
+    /// Depositor's bundle token account (receives bundle tokens)
+    #[account(
+        mut,
+        constraint = depositor_bundle_account.mint == bundle_asset.asset_token_mint,
+    )]
+    pub depositor_bundle_account: Account<'info, TokenAccount>,
+
+    /// Depositor of constituent tokens
+    pub depositor: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: for each component in order,
+    // [depositor component token account (mut), component vault (mut)]
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BundleTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let bundle = &ctx.accounts.asset_bundle;
+    require!(!bundle.components.is_empty(), MarketplaceError::InvalidBundleAccounts);
+    require!(
+        ctx.remaining_accounts.len() == bundle.components.len() * 2,
+        MarketplaceError::InvalidBundleAccounts
+    );
+
+    // Deposit each constituent into its vault
+    for (component, accounts) in bundle
+        .components
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(2))
+    {
+        let source = Account::<TokenAccount>::try_from(&accounts[0])?;
+        require!(
+            source.mint == component.asset_token_mint
+                && accounts[1].key() == component.vault,
+            MarketplaceError::InvalidBundleAccounts
+        );
+
+        let units = component
+            .units_per_bundle
+            .checked_mul(amount)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts[0].clone(),
+                to: accounts[1].clone(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, units)?;
+    }
+
+    // Mint bundle tokens to the depositor
+    let asset = &ctx.accounts.bundle_asset;
+    let lot_id_bytes = asset.parking_lot_id.to_le_bytes();
+    let seeds = &[
+        b"parking_asset",
+        lot_id_bytes.as_ref(),
+        asset.spot_number.as_bytes(),
+        &[asset.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.bundle_mint.to_account_info(),
+            to: ctx.accounts.depositor_bundle_account.to_account_info(),
+            authority: asset.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, amount)?;
+
+    let asset = &mut ctx.accounts.bundle_asset;
+    asset.total_supply = asset
+        .total_supply
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    asset.circulating_supply = asset
+        .circulating_supply
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!("Bundled {} tokens of {}", amount, asset.spot_number);
+
+    Ok(())
+}
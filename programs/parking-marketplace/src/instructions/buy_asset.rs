@@ -9,15 +9,25 @@ pub struct BuyAsset<'info> {
     #[account(
         mut,
         constraint = listing_account.status == ListingStatus::Active @ MarketplaceError::ListingNotActive,
+        constraint = listing_account.listing_kind == ListingKind::AssetTokens @ MarketplaceError::InvalidListingKind,
     )]
     pub listing_account: Account<'info, MarketplaceListing>,
 
     /// Asset account
     #[account(
         constraint = asset_account.is_tradeable @ MarketplaceError::AssetNotTradeable,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
     )]
     pub asset_account: Account<'info, ParkingAsset>,
 
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     /// Buyer
     #[account(mut)]
     pub buyer: Signer<'info>,
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct BuyReservation<'info> {
+    /// Listing account
+    #[account(
+        mut,
+        constraint = listing_account.status == ListingStatus::Active @ MarketplaceError::ListingNotActive,
+        constraint = listing_account.listing_kind == ListingKind::Reservation @ MarketplaceError::InvalidListingKind,
+        constraint = listing_account.reservation == reservation.key() @ MarketplaceError::InvalidListingKind,
+    )]
+    pub listing_account: Account<'info, MarketplaceListing>,
+
+    /// Reservation being bought
+    #[account(
+        mut,
+        constraint = reservation.driver == listing_account.seller @ MarketplaceError::ReservationNotResellable,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// Spot calendar (resale policy, booking holder)
+    #[account(
+        mut,
+        seeds = [b"spot_calendar", reservation.asset_account.as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Parking asset the reservation is for
+    #[account(
+        address = reservation.asset_account,
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Asset revenue vault (receives the resale fee)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            reservation.asset_account.as_ref(),
+            reservation.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Buyer
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's payment token account
+    #[account(
+        mut,
+        constraint = buyer_payment_account.owner == buyer.key(),
+        constraint = buyer_payment_account.mint == reservation.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub buyer_payment_account: Account<'info, TokenAccount>,
+
+    /// Seller's payment token account (receives payment net of fee)
+    #[account(
+        mut,
+        constraint = seller_payment_account.owner == listing_account.seller,
+        constraint = seller_payment_account.mint == reservation.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub seller_payment_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<BuyReservation>,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing_account;
+    let clock = Clock::get()?;
+
+    // Validate listing and reservation are still valid
+    require!(
+        listing.is_valid(clock.unix_timestamp),
+        MarketplaceError::ListingExpired
+    );
+    require!(
+        ctx.accounts.reservation.is_resellable(clock.unix_timestamp),
+        MarketplaceError::ReservationNotResellable
+    );
+    let buyer_key = ctx.accounts.buyer.key();
+    let calendar = &mut ctx.accounts.spot_calendar;
+    calendar.prune(clock.unix_timestamp);
+    require!(
+        calendar.bookings_held_by(&buyer_key) < SpotCalendar::MAX_BOOKINGS_PER_DRIVER,
+        MarketplaceError::DriverBookingLimitReached
+    );
+    calendar.reassign(ctx.accounts.reservation.start_time, buyer_key);
+
+    // Split the price between the revenue vault and the seller
+    let price_usdc = listing.total_price_usdc;
+    let fee_usdc = calendar.resale_policy.fee(price_usdc);
+    let seller_amount_usdc = price_usdc - fee_usdc;
+
+    if fee_usdc > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer_payment_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, fee_usdc)?;
+
+        ctx.accounts
+            .revenue_vault
+            .record_inflow(fee_usdc, clock.unix_timestamp)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    }
+
+    if seller_amount_usdc > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer_payment_account.to_account_info(),
+                to: ctx.accounts.seller_payment_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, seller_amount_usdc)?;
+    }
+
+    // Hand the reservation to the buyer
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.driver = buyer_key;
+    reservation.price_paid_usdc = price_usdc;
+
+    let listing = &mut ctx.accounts.listing_account;
+    listing.token_amount = 0;
+    listing.status = ListingStatus::Sold;
+
+    msg!(
+        "Reservation {} resold for {} ({} fee)",
+        reservation.key(),
+        price_usdc,
+        fee_usdc
+    );
+
+    Ok(())
+}
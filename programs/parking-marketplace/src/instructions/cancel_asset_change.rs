@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CancelAssetChange<'info> {
+    /// Timelocked change (closed, rent returned to the operator who scheduled it)
+    #[account(
+        mut,
+        close = scheduler,
+        constraint = timelocked_change.asset_account == asset_account.key() @ MarketplaceError::InvalidTimelockedChange,
+    )]
+    pub timelocked_change: Account<'info, TimelockedChange>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == authority.key()
+            || timelocked_change.operator == authority.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// CHECK: Operator who scheduled the change, receives the rent
+    #[account(
+        mut,
+        address = timelocked_change.operator,
+    )]
+    pub scheduler: UncheckedAccount<'info>,
+
+    /// Current operator, or the operator who scheduled the change
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<CancelAssetChange>,
+) -> Result<()> {
+    msg!(
+        "Scheduled asset change cancelled for {}",
+        ctx.accounts.asset_account.key()
+    );
+
+    Ok(())
+}
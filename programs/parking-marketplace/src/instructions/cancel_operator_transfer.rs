@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CancelOperatorTransfer<'info> {
+    /// Operator transfer (closed, rent returned to the current operator)
+    #[account(
+        mut,
+        close = current_operator,
+        seeds = [
+            b"operator_transfer",
+            operator_transfer.parking_lot_id.to_le_bytes().as_ref(),
+            operator_transfer.current_operator.as_ref()
+        ],
+        bump = operator_transfer.bump,
+        constraint = authority.key() == operator_transfer.current_operator
+            || authority.key() == operator_transfer.proposed_operator @ MarketplaceError::UnauthorizedOperator,
+        // A partly executed transfer must be completed, not abandoned
+        constraint = operator_transfer.assets_transferred == 0 @ MarketplaceError::InvalidOperatorTransfer,
+    )]
+    pub operator_transfer: Account<'info, OperatorTransfer>,
+
+    /// CHECK: Current operator, receives the transfer account's rent
+    #[account(
+        mut,
+        address = operator_transfer.current_operator,
+    )]
+    pub current_operator: UncheckedAccount<'info>,
+
+    /// Current or proposed operator
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<CancelOperatorTransfer>,
+) -> Result<()> {
+    let transfer = &ctx.accounts.operator_transfer;
+
+    msg!(
+        "Operator transfer cancelled for lot {} by {}",
+        transfer.parking_lot_id,
+        ctx.accounts.authority.key()
+    );
+
+    Ok(())
+}
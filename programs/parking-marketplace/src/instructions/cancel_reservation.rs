@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CancelReservation<'info> {
+    /// Reservation being cancelled (closed so the start time can be booked again)
+    #[account(
+        mut,
+        close = driver,
+        constraint = reservation.driver == driver.key() @ MarketplaceError::ReservationNotCancellable,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// Spot calendar
+    #[account(
+        mut,
+        seeds = [b"spot_calendar", reservation.asset_account.as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Asset revenue vault the reservation was paid into
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            reservation.asset_account.as_ref(),
+            reservation.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Driver's payment token account (receives the refund)
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == driver.key(),
+        constraint = driver_payment_account.mint == reservation.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// Driver holding the reservation
+    #[account(mut)]
+    pub driver: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<CancelReservation>,
+) -> Result<()> {
+    let reservation = &ctx.accounts.reservation;
+    let clock = Clock::get()?;
+
+    require!(
+        reservation.is_cancellable(clock.unix_timestamp),
+        MarketplaceError::ReservationNotCancellable
+    );
+
+    // Free the slot for other drivers
+    ctx.accounts.spot_calendar.release(reservation.start_time);
+
+    // Refund what the vault received at booking, provided it has not been
+    // distributed yet; resale proceeds went to the seller, not the vault
+    let refund_usdc = reservation.original_price_usdc;
+    if refund_usdc > 0 {
+        let revenue_vault = &mut ctx.accounts.revenue_vault;
+        revenue_vault
+            .record_refund(refund_usdc)
+            .ok_or(MarketplaceError::RefundUnavailable)?;
+
+        let asset_key = revenue_vault.asset_account;
+        let mint_key = revenue_vault.mint;
+        let seeds = &[
+            b"revenue_vault",
+            asset_key.as_ref(),
+            mint_key.as_ref(),
+            &[revenue_vault.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.driver_payment_account.to_account_info(),
+                authority: revenue_vault.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund_usdc)?;
+    }
+
+    msg!(
+        "Reservation {} cancelled, {} refunded",
+        reservation.key(),
+        refund_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::verification::verify_balance_snapshot;
+
+#[derive(Accounts)]
+pub struct ClaimRevenue<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Parking asset
+    #[account(
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Claim record PDA (prevents claiming twice)
+    #[account(
+        init,
+        payer = holder,
+        space = RevenueClaim::LEN,
+        seeds = [
+            b"revenue_claim",
+            distribution_account.key().as_ref(),
+            holder.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim_account: Account<'info, RevenueClaim>,
+
+    /// Distribution vault (holds payouts in the distribution's payout mint)
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Holder's payout token account (receives revenue)
+    #[account(
+        mut,
+        constraint = holder_payout_account.owner == holder.key(),
+        constraint = holder_payout_account.mint == distribution_account.payout_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub holder_payout_account: Account<'info, TokenAccount>,
+
+    /// Token holder
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimRevenue>,
+    token_balance: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let distribution = &mut ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.distribution_status == DistributionStatus::Processing,
+        MarketplaceError::InvalidDistributionStatus
+    );
+    require!(
+        !distribution.is_claim_window_closed(clock.unix_timestamp),
+        MarketplaceError::ClaimWindowClosed
+    );
+
+    // Entitlement comes from the snapshot, not the live balance, so tokens
+    // moved to a fresh wallet after the period cannot be claimed on twice
+    verify_balance_snapshot(
+        &distribution.holder_snapshot_root,
+        &ctx.accounts.holder.key(),
+        token_balance,
+        &proof,
+    )?;
+
+    let amount = token_balance
+        .checked_mul(distribution.revenue_per_token_usdc)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(amount > 0, MarketplaceError::NothingToClaim);
+
+    let total_distributed_usdc = distribution
+        .total_distributed_usdc
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let distributable_usdc = distribution
+        .distributable_amount()
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(
+        total_distributed_usdc <= distributable_usdc,
+        MarketplaceError::DistributionOverpaid
+    );
+
+    // Pay the holder from the distribution vault
+    let asset_key = distribution.asset_account;
+    let created_at_bytes = distribution.created_at.to_le_bytes();
+    let seeds = &[
+        b"revenue_distribution",
+        asset_key.as_ref(),
+        created_at_bytes.as_ref(),
+        &[distribution.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.distribution_vault.to_account_info(),
+            to: ctx.accounts.holder_payout_account.to_account_info(),
+            authority: distribution.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    distribution.total_distributed_usdc = total_distributed_usdc;
+
+    // Record the claim
+    let claim = &mut ctx.accounts.claim_account;
+    claim.distribution_account = distribution.key();
+    claim.holder = ctx.accounts.holder.key();
+    claim.token_balance = token_balance;
+    claim.amount = amount;
+    claim.claimed_at = clock.unix_timestamp;
+    claim.bump = ctx.bumps.claim_account;
+
+    msg!(
+        "Revenue claimed: {} tokens of mint {} for {} asset tokens",
+        amount,
+        distribution.payout_mint,
+        token_balance
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ClaimTenderPayment<'info> {
+    /// Tender offer
+    #[account(
+        seeds = [
+            b"tender_offer",
+            tender_offer.asset_account.as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+        constraint = tender_offer.status == TenderOfferStatus::Settled @ MarketplaceError::TenderOfferNotSettled,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Parking asset the tender offer is for
+    #[account(
+        address = tender_offer.asset_account,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Tender position (closed, rent returned to holder)
+    #[account(
+        mut,
+        close = holder,
+        seeds = [
+            b"tender_position",
+            tender_offer.key().as_ref(),
+            holder.key().as_ref()
+        ],
+        bump = tender_position.bump,
+    )]
+    pub tender_position: Account<'info, TenderPosition>,
+
+    /// Payment escrow
+    #[account(
+        mut,
+        seeds = [b"tender_escrow", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub payment_escrow: Account<'info, TokenAccount>,
+
+    /// Holder's payment token account
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == tender_offer.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub holder_payment_account: Account<'info, TokenAccount>,
+
+    /// Holder who tendered
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimTenderPayment>,
+) -> Result<()> {
+    let offer = &ctx.accounts.tender_offer;
+    let amount = ctx.accounts.tender_position.amount;
+    let payment_usdc = offer
+        .payment_for(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let asset_key = offer.asset_account;
+    let bidder_key = offer.bidder;
+    let seeds = &[
+        b"tender_offer",
+        asset_key.as_ref(),
+        bidder_key.as_ref(),
+        &[offer.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payment_escrow.to_account_info(),
+            to: ctx.accounts.holder_payment_account.to_account_info(),
+            authority: offer.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, payment_usdc)?;
+
+    msg!(
+        "Tender payment claimed: {} for {} tokens",
+        payment_usdc,
+        amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CloseBuyback<'info> {
+    /// Buyback account (closed, rent returned to operator)
+    #[account(
+        mut,
+        close = operator,
+        seeds = [b"buyback", buyback.asset_account.as_ref()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Account<'info, Buyback>,
+
+    /// Parking asset being bought back
+    #[account(
+        address = buyback.asset_account,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Buyback payment vault (closed after refunding)
+    #[account(
+        mut,
+        seeds = [b"buyback_vault", buyback.key().as_ref()],
+        bump,
+    )]
+    pub buyback_vault: Account<'info, TokenAccount>,
+
+    /// Operator's payment token account (receives unused funds)
+    #[account(
+        mut,
+        constraint = operator_payment_account.owner == operator.key(),
+        constraint = operator_payment_account.mint == buyback.payment_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payment_account: Account<'info, TokenAccount>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<CloseBuyback>,
+) -> Result<()> {
+    let buyback = &ctx.accounts.buyback;
+    let clock = Clock::get()?;
+
+    require!(
+        !buyback.is_open(clock.unix_timestamp),
+        MarketplaceError::BuybackStillOpen
+    );
+
+    let asset_key = buyback.asset_account;
+    let seeds = &[
+        b"buyback",
+        asset_key.as_ref(),
+        &[buyback.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Refund whatever holders did not redeem
+    let unused = ctx.accounts.buyback_vault.amount;
+    if unused > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyback_vault.to_account_info(),
+                to: ctx.accounts.operator_payment_account.to_account_info(),
+                authority: buyback.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, unused)?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.buyback_vault.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: buyback.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    msg!(
+        "Buyback closed: {} tokens redeemed, {} refunded",
+        buyback.tokens_redeemed,
+        unused
+    );
+
+    Ok(())
+}
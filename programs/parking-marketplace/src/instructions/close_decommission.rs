@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, CloseAccount, Mint, SetAuthority, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CloseDecommission<'info> {
+    /// Decommission account (closed, rent returned to operator)
+    #[account(
+        mut,
+        close = operator,
+        seeds = [b"decommission", asset_account.key().as_ref()],
+        bump = decommission.bump,
+    )]
+    pub decommission: Account<'info, Decommission>,
+
+    /// Vault holding the sale proceeds (closed after sweeping)
+    #[account(
+        mut,
+        seeds = [b"decommission_vault", decommission.key().as_ref()],
+        bump,
+    )]
+    pub decommission_vault: Account<'info, TokenAccount>,
+
+    /// Parking asset (closed, rent returned to operator, once no tokens
+    /// are outstanding)
+    #[account(
+        mut,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset token mint (mint authority is revoked)
+    #[account(
+        mut,
+        address = asset_account.asset_token_mint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Token account receiving unclaimed proceeds
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == decommission.payment_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// Protocol config (reserve fund)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<CloseDecommission>,
+) -> Result<()> {
+    let decommission = &ctx.accounts.decommission;
+    let clock = Clock::get()?;
+
+    require!(
+        decommission.proceeds_finalized,
+        MarketplaceError::ProceedsNotFinalized
+    );
+    // Close early only once every token has been redeemed
+    require!(
+        !decommission.is_redemption_open(clock.unix_timestamp)
+            || ctx.accounts.asset_account.circulating_supply == 0,
+        MarketplaceError::RedemptionWindowOpen
+    );
+
+    // Unclaimed proceeds go where the operator committed to at decommissioning
+    let expected_owner = match decommission.unclaimed_destination {
+        SweepDestination::ReserveFund => ctx.accounts.protocol_config.reserve_fund,
+        _ => ctx.accounts.asset_account.institutional_operator,
+    };
+    require!(
+        ctx.accounts.destination_token_account.owner == expected_owner,
+        MarketplaceError::InvalidSweepDestination
+    );
+
+    let asset_key = decommission.asset_account;
+    let seeds = &[
+        b"decommission",
+        asset_key.as_ref(),
+        &[decommission.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let unclaimed = ctx.accounts.decommission_vault.amount;
+    if unclaimed > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.decommission_vault.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: decommission.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, unclaimed)?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.decommission_vault.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: decommission.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    // No more asset tokens can ever be minted
+    let asset = &ctx.accounts.asset_account;
+    let lot_id_bytes = asset.parking_lot_id.to_le_bytes();
+    let asset_seeds = &[
+        b"parking_asset",
+        lot_id_bytes.as_ref(),
+        asset.spot_number.as_bytes(),
+        &[asset.bump],
+    ];
+    let asset_signer = &[&asset_seeds[..]];
+
+    let authority_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        SetAuthority {
+            account_or_mint: ctx.accounts.token_mint.to_account_info(),
+            current_authority: asset.to_account_info(),
+        },
+        asset_signer,
+    );
+    token::set_authority(authority_ctx, AuthorityType::MintTokens, None)?;
+
+    // Tokens not redeemed in time keep their asset record, so they still
+    // resolve to a decommissioned asset rather than a missing account
+    let outstanding = asset.circulating_supply;
+    if outstanding == 0 {
+        ctx.accounts
+            .asset_account
+            .close(ctx.accounts.operator.to_account_info())?;
+    }
+
+    msg!(
+        "Decommission closed for asset {}: {} paid, {} unclaimed, {} tokens unredeemed",
+        asset_key,
+        decommission.amount_paid_usdc,
+        unclaimed,
+        outstanding
+    );
+
+    Ok(())
+}
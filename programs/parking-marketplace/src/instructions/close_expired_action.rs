@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CloseExpiredAction<'info> {
+    /// Pending action (closed, rent returned to the proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            b"pending_action",
+            pending_action.multisig.as_ref(),
+            pending_action.index.to_le_bytes().as_ref()
+        ],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// CHECK: Proposer, receives the pending action's rent
+    #[account(
+        mut,
+        address = pending_action.proposer,
+    )]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<CloseExpiredAction>,
+) -> Result<()> {
+    let action = &ctx.accounts.pending_action;
+    let clock = Clock::get()?;
+
+    require!(
+        action.is_expired(clock.unix_timestamp),
+        MarketplaceError::ActionNotExpired
+    );
+
+    msg!(
+        "Expired action {} closed",
+        action.index
+    );
+
+    Ok(())
+}
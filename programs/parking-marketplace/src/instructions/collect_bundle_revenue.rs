@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::verification::verify_balance_snapshot;
+
+#[derive(Accounts)]
+pub struct CollectBundleRevenue<'info> {
+    /// Constituent revenue distribution
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == component_asset.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Distribution vault (holds payouts in the distribution's payout mint)
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Claim record PDA for the bundle (prevents claiming twice)
+    #[account(
+        init,
+        payer = payer,
+        space = RevenueClaim::LEN,
+        seeds = [
+            b"revenue_claim",
+            distribution_account.key().as_ref(),
+            bundle_asset.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim_account: Account<'info, RevenueClaim>,
+
+    /// Constituent asset
+    #[account(
+        constraint = !component_asset.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub component_asset: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Bundle composition
+    #[account(
+        seeds = [b"asset_bundle", bundle_asset.key().as_ref()],
+        bump = asset_bundle.bump,
+    )]
+    pub asset_bundle: Account<'info, AssetBundle>,
+
+    /// Bundle asset
+    pub bundle_asset: Account<'info, ParkingAsset>,
+
+    /// Bundle asset revenue vault (receives the constituent revenue)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            bundle_asset.key().as_ref(),
+            distribution_account.payout_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding the bundle's collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone may crank revenue into the bundle
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CollectBundleRevenue>,
+    token_balance: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts
+            .asset_bundle
+            .find_component(&ctx.accounts.component_asset.key())
+            .is_some(),
+        MarketplaceError::InvalidBundleComponent
+    );
+
+    let distribution = &mut ctx.accounts.distribution_account;
+    require!(
+        distribution.distribution_status == DistributionStatus::Processing,
+        MarketplaceError::InvalidDistributionStatus
+    );
+    require!(
+        !distribution.is_claim_window_closed(clock.unix_timestamp),
+        MarketplaceError::ClaimWindowClosed
+    );
+
+    // Claim for the constituent tokens the bundle vault (owned by the bundle
+    // asset) held in the snapshot; later deposits earn from the next period
+    verify_balance_snapshot(
+        &distribution.holder_snapshot_root,
+        &ctx.accounts.bundle_asset.key(),
+        token_balance,
+        &proof,
+    )?;
+
+    let amount = token_balance
+        .checked_mul(distribution.revenue_per_token_usdc)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(amount > 0, MarketplaceError::NothingToClaim);
+
+    let total_distributed_usdc = distribution
+        .total_distributed_usdc
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let distributable_usdc = distribution
+        .distributable_amount()
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(
+        total_distributed_usdc <= distributable_usdc,
+        MarketplaceError::DistributionOverpaid
+    );
+
+    let asset_key = distribution.asset_account;
+    let created_at_bytes = distribution.created_at.to_le_bytes();
+    let seeds = &[
+        b"revenue_distribution",
+        asset_key.as_ref(),
+        created_at_bytes.as_ref(),
+        &[distribution.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.distribution_vault.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: distribution.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    distribution.total_distributed_usdc = total_distributed_usdc;
+
+    // Constituent revenue becomes bundle revenue for the bundle's own distributions
+    ctx.accounts
+        .revenue_vault
+        .record_inflow(amount, clock.unix_timestamp)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let claim = &mut ctx.accounts.claim_account;
+    claim.distribution_account = distribution.key();
+    claim.holder = ctx.accounts.bundle_asset.key();
+    claim.token_balance = token_balance;
+    claim.amount = amount;
+    claim.claimed_at = clock.unix_timestamp;
+    claim.bump = ctx.bumps.claim_account;
+
+    msg!(
+        "Bundle revenue collected: {} for {} constituent tokens",
+        amount,
+        token_balance
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CompleteReservation<'info> {
+    /// Reservation being completed
+    #[account(mut)]
+    pub reservation: Account<'info, Reservation>,
+
+    /// Spot calendar
+    #[account(
+        mut,
+        seeds = [b"spot_calendar", reservation.asset_account.as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Driver checking out early, or anyone once the reservation has ended
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<CompleteReservation>,
+) -> Result<()> {
+    let reservation = &mut ctx.accounts.reservation;
+    let clock = Clock::get()?;
+
+    require!(
+        reservation.status == ReservationStatus::Active,
+        MarketplaceError::ReservationNotCompletable
+    );
+
+    // The driver may check out once the reservation starts; anyone may close it out after it ends
+    let is_driver = ctx.accounts.authority.key() == reservation.driver;
+    require!(
+        (is_driver && clock.unix_timestamp >= reservation.start_time)
+            || clock.unix_timestamp >= reservation.end_time,
+        MarketplaceError::ReservationNotCompletable
+    );
+
+    ctx.accounts.spot_calendar.release(reservation.start_time);
+    reservation.status = ReservationStatus::Completed;
+
+    msg!(
+        "Reservation {} completed at {}",
+        reservation.key(),
+        clock.unix_timestamp
+    );
+
+    Ok(())
+}
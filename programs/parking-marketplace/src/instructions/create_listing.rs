@@ -23,9 +23,18 @@ pub struct CreateListing<'info> {
     #[account(
         constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
         constraint = asset_account.is_tradeable @ MarketplaceError::AssetNotTradeable,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
     )]
     pub asset_account: Account<'info, ParkingAsset>,
 
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     /// Seller (must own the tokens)
     #[account(mut)]
     pub seller: Signer<'info>,
@@ -64,6 +73,8 @@ pub fn handler(
     listing.asset_account = ctx.accounts.asset_account.key();
     listing.seller = ctx.accounts.seller.key();
     listing.listing_type = listing_type;
+    listing.listing_kind = ListingKind::AssetTokens;
+    listing.reservation = Pubkey::default();
     listing.token_amount = token_amount;
     listing.price_per_token_usdc = price_per_token_usdc;
     listing.total_price_usdc = total_price_usdc;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CreateOperatorMultisig<'info> {
+    /// Operator multisig PDA
+    #[account(
+        init,
+        payer = creator,
+        space = OperatorMultisig::LEN,
+        seeds = [b"operator_multisig", create_key.key().as_ref()],
+        bump
+    )]
+    pub operator_multisig: Account<'info, OperatorMultisig>,
+
+    /// CHECK: System-owned signer PDA that acts as the institutional operator
+    #[account(
+        seeds = [b"multisig_signer", operator_multisig.key().as_ref()],
+        bump
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    /// Unique key the multisig is derived from
+    pub create_key: Signer<'info>,
+
+    /// Payer creating the multisig
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateOperatorMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        OperatorMultisig::is_valid_config(&signers, threshold),
+        MarketplaceError::InvalidMultisigConfig
+    );
+
+    let multisig = &mut ctx.accounts.operator_multisig;
+
+    multisig.create_key = ctx.accounts.create_key.key();
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.action_count = 0;
+    multisig.signer_bump = ctx.bumps.multisig_signer;
+    multisig.bump = ctx.bumps.operator_multisig;
+
+    msg!(
+        "Operator multisig created: {} of {} signers, operator key {}",
+        threshold,
+        multisig.signers.len(),
+        ctx.accounts.multisig_signer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct CreateSubscriptionOffer<'info> {
+    /// Subscription offer PDA
+    #[account(
+        init,
+        payer = operator,
+        space = SubscriptionOffer::LEN,
+        seeds = [
+            b"subscription_offer",
+            asset_account.key().as_ref(),
+            &[tier]
+        ],
+        bump
+    )]
+    pub offer_account: Account<'info, SubscriptionOffer>,
+
+    /// Parking asset receiving subscription revenue
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault (fixes the payment mint)
+    #[account(
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            revenue_vault.mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateSubscriptionOffer>,
+    tier: u8,
+    price_usdc: u64,
+    duration_seconds: i64,
+) -> Result<()> {
+    require!(price_usdc > 0, MarketplaceError::InvalidPrice);
+    require!(
+        (SubscriptionOffer::MIN_DURATION_SECONDS..=SubscriptionOffer::MAX_DURATION_SECONDS)
+            .contains(&duration_seconds),
+        MarketplaceError::InvalidSubscriptionPeriod
+    );
+
+    let offer = &mut ctx.accounts.offer_account;
+
+    offer.asset_account = ctx.accounts.asset_account.key();
+    offer.parking_lot_id = ctx.accounts.asset_account.parking_lot_id;
+    offer.tier = tier;
+    offer.price_usdc = price_usdc;
+    offer.duration_seconds = duration_seconds;
+    offer.payment_mint = ctx.accounts.revenue_vault.mint;
+    offer.is_active = true;
+    offer.bump = ctx.bumps.offer_account;
+
+    msg!(
+        "Subscription offer created: lot {} tier {} at {} per {} seconds",
+        offer.parking_lot_id,
+        tier,
+        price_usdc,
+        duration_seconds
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct CreateTenderOffer<'info> {
+    /// Tender offer PDA
+    #[account(
+        init,
+        payer = bidder,
+        space = TenderOffer::LEN,
+        seeds = [
+            b"tender_offer",
+            asset_account.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Payment escrow covering the whole outstanding supply
+    #[account(
+        init,
+        payer = bidder,
+        seeds = [b"tender_escrow", tender_offer.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = tender_offer,
+    )]
+    pub payment_escrow: Account<'info, TokenAccount>,
+
+    /// Escrow holding tendered asset tokens
+    #[account(
+        init,
+        payer = bidder,
+        seeds = [b"tender_tokens", tender_offer.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = tender_offer,
+    )]
+    pub token_escrow: Account<'info, TokenAccount>,
+
+    /// Payment token mint
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payment_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Asset token mint
+    #[account(address = asset_account.asset_token_mint)]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Parking asset being acquired
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.is_tradeable @ MarketplaceError::AssetNotTradeable,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (payment mint allowlist, pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Bidder's asset token account (receives acquired tokens)
+    #[account(
+        constraint = bidder_token_account.owner == bidder.key(),
+        constraint = bidder_token_account.mint == token_mint.key(),
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+
+    /// Bidder's payment token account (funds the escrow)
+    #[account(
+        mut,
+        constraint = bidder_payment_account.owner == bidder.key(),
+        constraint = bidder_payment_account.mint == payment_mint.key() @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub bidder_payment_account: Account<'info, TokenAccount>,
+
+    /// Acquirer
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTenderOffer>,
+    price_per_token_usdc: u64,
+    threshold_bps: u16,
+    duration_seconds: i64,
+) -> Result<()> {
+    require!(
+        price_per_token_usdc > 0
+            && duration_seconds > 0
+            && duration_seconds <= TenderOffer::MAX_DURATION_SECONDS,
+        MarketplaceError::InvalidTenderOffer
+    );
+    require!(
+        (TenderOffer::MIN_THRESHOLD_BPS..=10000).contains(&threshold_bps),
+        MarketplaceError::InvalidTenderOffer
+    );
+
+    let asset = &ctx.accounts.asset_account;
+    let clock = Clock::get()?;
+
+    // Holders who never tender are bought out at this price, so it may not
+    // undercut the asset's estimated value
+    require!(
+        TenderOffer::meets_price_floor(
+            price_per_token_usdc,
+            asset.total_supply,
+            asset.estimated_value_usdc,
+        ),
+        MarketplaceError::TenderPriceBelowValuation
+    );
+
+    // Escrow enough to buy every outstanding token the bidder does not
+    // already hold; the bidder's own tokens never count toward the threshold
+    let tokens_sought = asset
+        .circulating_supply
+        .saturating_sub(ctx.accounts.bidder_token_account.amount);
+    require!(tokens_sought > 0, MarketplaceError::InvalidTenderOffer);
+    let escrow_usdc = price_per_token_usdc
+        .checked_mul(tokens_sought)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.bidder_payment_account.to_account_info(),
+            to: ctx.accounts.payment_escrow.to_account_info(),
+            authority: ctx.accounts.bidder.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, escrow_usdc)?;
+
+    let offer = &mut ctx.accounts.tender_offer;
+    offer.asset_account = asset.key();
+    offer.bidder = ctx.accounts.bidder.key();
+    offer.bidder_token_account = ctx.accounts.bidder_token_account.key();
+    offer.payment_mint = ctx.accounts.payment_mint.key();
+    offer.price_per_token_usdc = price_per_token_usdc;
+    offer.tokens_sought = tokens_sought;
+    offer.threshold_bps = threshold_bps;
+    offer.tokens_tendered = 0;
+    offer.tokens_surrendered = 0;
+    offer.expires_at = clock
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    offer.status = TenderOfferStatus::Open;
+    offer.created_at = clock.unix_timestamp;
+    offer.bump = ctx.bumps.tender_offer;
+
+    msg!(
+        "Tender offer created for {} tokens at {} per token, threshold {} bps",
+        tokens_sought,
+        price_per_token_usdc,
+        threshold_bps
+    );
+
+    Ok(())
+}
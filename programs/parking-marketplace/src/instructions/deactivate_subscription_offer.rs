@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct DeactivateSubscriptionOffer<'info> {
+    /// Subscription offer
+    #[account(
+        mut,
+        constraint = offer_account.asset_account == asset_account.key() @ MarketplaceError::InvalidSubscription,
+        constraint = offer_account.is_active @ MarketplaceError::InvalidSubscription,
+    )]
+    pub offer_account: Account<'info, SubscriptionOffer>,
+
+    /// Parking asset receiving subscription revenue
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<DeactivateSubscriptionOffer>,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer_account;
+
+    // Passes already sold stay valid until they expire
+    offer.is_active = false;
+
+    msg!(
+        "Subscription offer tier {} deactivated for asset {}",
+        offer.tier,
+        offer.asset_account
+    );
+
+    Ok(())
+}
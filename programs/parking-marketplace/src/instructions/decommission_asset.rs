@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct DecommissionAsset<'info> {
+    /// Decommission account PDA
+    #[account(
+        init,
+        payer = operator,
+        space = Decommission::LEN,
+        seeds = [b"decommission", asset_account.key().as_ref()],
+        bump
+    )]
+    pub decommission: Account<'info, Decommission>,
+
+    /// Vault holding the sale proceeds
+    #[account(
+        init,
+        payer = operator,
+        seeds = [b"decommission_vault", decommission.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = decommission,
+    )]
+    pub decommission_vault: Account<'info, TokenAccount>,
+
+    /// Token mint the proceeds are paid in
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payment_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Protocol config (payout mint allowlist)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Parking asset being wound down
+    #[account(
+        mut,
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Compliance authority, or the protocol admin if none is set
+    #[account(
+        constraint = approver.key() == protocol_config.decommission_approver() @ MarketplaceError::UnauthorizedComplianceAuthority,
+    )]
+    pub approver: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<DecommissionAsset>,
+    redemption_window_seconds: i64,
+    unclaimed_destination: SweepDestination,
+) -> Result<()> {
+    require!(
+        redemption_window_seconds >= Decommission::MIN_REDEMPTION_WINDOW_SECONDS,
+        MarketplaceError::InvalidDecommission
+    );
+    require!(
+        unclaimed_destination != SweepDestination::NextPeriod,
+        MarketplaceError::InvalidSweepDestination
+    );
+
+    let clock = Clock::get()?;
+
+    // Halt trading and new distributions
+    let asset = &mut ctx.accounts.asset_account;
+    asset.is_tradeable = false;
+    asset.is_active = false;
+
+    let decommission = &mut ctx.accounts.decommission;
+    decommission.asset_account = asset.key();
+    decommission.payment_mint = ctx.accounts.payment_mint.key();
+    decommission.proceeds_usdc = 0;
+    decommission.tokens_redeemed = 0;
+    decommission.amount_paid_usdc = 0;
+    decommission.proceeds_finalized = false;
+    decommission.redemption_window_seconds = redemption_window_seconds;
+    decommission.redemption_deadline = 0; // Set once the proceeds are finalized
+    decommission.unclaimed_destination = unclaimed_destination;
+    decommission.created_at = clock.unix_timestamp;
+    decommission.bump = ctx.bumps.decommission;
+
+    msg!(
+        "Asset {} decommissioned, awaiting sale proceeds",
+        asset.key()
+    );
+
+    Ok(())
+}
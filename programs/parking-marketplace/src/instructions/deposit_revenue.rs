@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct DepositRevenue<'info> {
+    /// Revenue vault
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            revenue_vault.mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Payer's token account (source of payment)
+    #[account(
+        mut,
+        constraint = payer_token_account.owner == payer.key(),
+        constraint = payer_token_account.mint == revenue_vault.mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub payer_token_account: Account<'info, TokenAccount>,
+
+    /// Payer (driver, payment processor, etc.)
+    pub payer: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<DepositRevenue>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidPrice);
+
+    let clock = Clock::get()?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    // Only the operator's deposits count as revenue activity for the period
+    // check; anyone else could otherwise block distributions with dust
+    let vault = &mut ctx.accounts.revenue_vault;
+    if ctx.accounts.payer.key() == ctx.accounts.asset_account.institutional_operator {
+        vault
+            .record_inflow(amount, clock.unix_timestamp)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    } else {
+        vault
+            .record_external_inflow(amount)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    }
+
+    msg!(
+        "Revenue deposited: {} tokens of mint {} for asset {}",
+        amount,
+        vault.mint,
+        vault.asset_account
+    );
+
+    Ok(())
+}
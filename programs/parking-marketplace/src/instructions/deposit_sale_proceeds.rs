@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct DepositSaleProceeds<'info> {
+    /// Decommission account
+    #[account(
+        mut,
+        seeds = [b"decommission", decommission.asset_account.as_ref()],
+        bump = decommission.bump,
+    )]
+    pub decommission: Account<'info, Decommission>,
+
+    /// Parking asset being wound down
+    #[account(
+        address = decommission.asset_account,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Vault holding the sale proceeds
+    #[account(
+        mut,
+        seeds = [b"decommission_vault", decommission.key().as_ref()],
+        bump,
+    )]
+    pub decommission_vault: Account<'info, TokenAccount>,
+
+    /// Operator's payment token account
+    #[account(
+        mut,
+        constraint = operator_payment_account.owner == operator.key(),
+        constraint = operator_payment_account.mint == decommission.payment_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payment_account: Account<'info, TokenAccount>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<DepositSaleProceeds>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let decommission = &mut ctx.accounts.decommission;
+
+    // Every holder must redeem against the same proceeds
+    require!(
+        !decommission.proceeds_finalized,
+        MarketplaceError::ProceedsFinalized
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.operator_payment_account.to_account_info(),
+            to: ctx.accounts.decommission_vault.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    decommission.proceeds_usdc = decommission
+        .proceeds_usdc
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Sale proceeds deposited: {} (total {})",
+        amount,
+        decommission.proceeds_usdc
+    );
+
+    Ok(())
+}
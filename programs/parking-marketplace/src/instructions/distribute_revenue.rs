@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::error::MarketplaceError;
+use crate::verification::verify_ed25519_signature;
 
 #[derive(Accounts)]
 pub struct DistributeRevenue<'info> {
@@ -18,10 +21,64 @@ pub struct DistributeRevenue<'info> {
     )]
     pub distribution_account: Account<'info, RevenueDistribution>,
 
+    /// Distribution vault (holds payouts in the distribution's payout mint)
+    #[account(
+        init,
+        payer = operator,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+        token::mint = payout_mint,
+        token::authority = distribution_account,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Token mint the distribution pays out in
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payout_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payout_mint: Account<'info, Mint>,
+
+    /// Asset revenue vault for the payout mint
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            payout_mint.key().as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Operator's payout token account (receives operating costs and rounding remainder)
+    #[account(
+        mut,
+        constraint = operator_payout_account.owner == operator.key(),
+        constraint = operator_payout_account.mint == payout_mint.key() @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payout_account: Account<'info, TokenAccount>,
+
+    /// Protocol config (payout mint allowlist, pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     /// Parking asset
     #[account(
+        mut,
         constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
         constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
     )]
     pub asset_account: Account<'info, ParkingAsset>,
 
@@ -29,19 +86,31 @@ pub struct DistributeRevenue<'info> {
     #[account(mut)]
     pub operator: Signer<'info>,
 
+    /// CHECK: Instructions sysvar, used to verify signed revenue reports
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
     /// System program
     pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(
     ctx: Context<DistributeRevenue>,
-    total_revenue_usdc: u64,
-    operating_costs_usdc: u64,
+    operating_costs: OperatingCosts,
     period_start: i64,
     period_end: i64,
+    declared_gap: bool,
+    claim_window_seconds: i64,
+    holder_snapshot: HolderSnapshot,
 ) -> Result<()> {
     let distribution = &mut ctx.accounts.distribution_account;
-    let asset = &ctx.accounts.asset_account;
+    let asset = &mut ctx.accounts.asset_account;
     let clock = Clock::get()?;
 
     // Validate period
@@ -53,28 +122,156 @@ pub fn handler(
         period_end <= clock.unix_timestamp,
         MarketplaceError::InvalidRevenuePeriod
     );
+    require!(
+        (RevenueDistribution::MIN_CLAIM_WINDOW_SECONDS
+            ..=RevenueDistribution::MAX_CLAIM_WINDOW_SECONDS)
+            .contains(&claim_window_seconds),
+        MarketplaceError::InvalidClaimWindow
+    );
+    require!(
+        holder_snapshot.is_valid(),
+        MarketplaceError::MissingSnapshotRoot
+    );
+    require!(
+        asset.is_valid_next_period(period_start, declared_gap),
+        MarketplaceError::RevenuePeriodNotContiguous
+    );
+
+    // Gross revenue is what the vault actually collected since the last distribution
+    let revenue_vault = &mut ctx.accounts.revenue_vault;
+    require!(
+        period_end >= revenue_vault.last_inflow_at,
+        MarketplaceError::RevenuePeriodExcludesInflows
+    );
+    let total_revenue_usdc = revenue_vault.undistributed_inflows();
+
+    // Bound each cost category by the asset's caps
+    let operating_costs_usdc = operating_costs
+        .total()
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(
+        asset.cost_caps.permits(&operating_costs, total_revenue_usdc),
+        MarketplaceError::OperatingCostCapExceeded
+    );
+
+    // Require a signed report from the asset's revenue oracle. Claims are
+    // paid from the holder snapshot, so an unattested root would let the
+    // operator assign the whole holder pool to any wallet
+    require!(
+        asset.requires_revenue_attestation(),
+        MarketplaceError::RevenueAttestationRequired
+    );
+    require!(
+        ctx.accounts.protocol_config.is_revenue_oracle(&asset.revenue_oracle),
+        MarketplaceError::RevenueOracleNotRegistered
+    );
+
+    let report = RevenueReport {
+        asset_account: asset.key(),
+        nonce: asset.revenue_report_nonce,
+        payout_mint: ctx.accounts.payout_mint.key(),
+        period_start,
+        period_end,
+        total_revenue_usdc,
+        operating_costs,
+        holder_snapshot,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &asset.revenue_oracle,
+        &report.try_to_vec()?,
+    )?;
 
     // Initialize distribution
     distribution.asset_account = asset.key();
     distribution.period_start = period_start;
     distribution.period_end = period_end;
+    distribution.payout_mint = ctx.accounts.payout_mint.key();
+    distribution.previous_period_end = asset.last_distributed_period_end;
+    distribution.gap_declared = declared_gap && asset.last_distributed_period_end != 0;
     distribution.total_revenue_usdc = total_revenue_usdc;
     distribution.operating_costs_usdc = operating_costs_usdc;
-    distribution.total_tokens_outstanding = asset.circulating_supply;
+    distribution.cost_breakdown = operating_costs;
+    distribution.revenue_share_percentage = asset.revenue_share_percentage;
+    // Divide by the snapshot's supply so burns or issuance after period end
+    // cannot leave claims short or funds stranded
+    distribution.total_tokens_outstanding = holder_snapshot.total_supply;
+    distribution.holder_snapshot_root = holder_snapshot.root;
     distribution.operator = ctx.accounts.operator.key();
     distribution.created_at = clock.unix_timestamp;
     distribution.completed_at = 0;
+    distribution.claim_window_seconds = claim_window_seconds;
+    distribution.claim_deadline = 0; // Set by start_distribution
+    distribution.reconciled_at = 0;
+    distribution.failure_reason = DistributionFailureReason::None;
+    distribution.attested_by = asset.revenue_oracle;
     distribution.distribution_status = DistributionStatus::Pending;
     distribution.bump = ctx.bumps.distribution_account;
 
-    // Calculate net revenue and per-token distribution
+    // Calculate the holders' net revenue and per-token distribution
     distribution.calculate_net_revenue();
     distribution.calculate_revenue_per_token();
 
+    // Advance the asset's revenue history
+    asset.last_distributed_period_end = period_end;
+    asset.revenue_report_nonce = asset
+        .revenue_report_nonce
+        .checked_add(1)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    revenue_vault.distributed_inflows = revenue_vault.cumulative_inflows;
+
+    // Fund the distribution vault; costs, the operator's share and rounding
+    // remainder go to the operator
+    let distributable_usdc = distribution
+        .distributable_amount()
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let operator_share_usdc = total_revenue_usdc
+        .checked_sub(distributable_usdc)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    distribution.vault_funded_usdc = distributable_usdc;
+    distribution.operator_share_usdc = operator_share_usdc;
+
+    let asset_key = asset.key();
+    let mint_key = revenue_vault.mint;
+    let seeds = &[
+        b"revenue_vault",
+        asset_key.as_ref(),
+        mint_key.as_ref(),
+        &[revenue_vault.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if distributable_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+                authority: revenue_vault.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, distributable_usdc)?;
+    }
+
+    if operator_share_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.operator_payout_account.to_account_info(),
+                authority: revenue_vault.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, operator_share_usdc)?;
+    }
+
     msg!(
-        "Revenue distribution created: {} USDC net revenue, {} USDC per token",
+        "Revenue distribution created: {} net revenue, {} per token in mint {}",
         distribution.net_revenue_usdc,
-        distribution.revenue_per_token_usdc
+        distribution.revenue_per_token_usdc,
+        distribution.payout_mint
     );
 
     Ok(())
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::verification::verify_ed25519_signature;
+
+#[derive(Accounts)]
+pub struct EndChargingSession<'info> {
+    /// Charging session
+    #[account(
+        mut,
+        constraint = session_account.station_account == station_account.key() @ MarketplaceError::InvalidSession,
+        constraint = session_account.status == SessionStatus::Active @ MarketplaceError::SessionNotActive,
+    )]
+    pub session_account: Account<'info, ChargingSession>,
+
+    /// Session escrow (closed at settlement)
+    #[account(
+        mut,
+        seeds = [b"session_escrow", session_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Charging station
+    #[account(
+        mut,
+        seeds = [b"charging_station", asset_account.key().as_ref()],
+        bump = station_account.bump,
+    )]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Parking asset owning the station
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault (receives the charge)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            session_account.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Driver's payment token account (receives the refund)
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == session_account.driver,
+        constraint = driver_payment_account.mint == session_account.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Driver wallet, receives escrow rent; validated against the session
+    #[account(
+        mut,
+        address = session_account.driver @ MarketplaceError::InvalidSession,
+    )]
+    pub driver: UncheckedAccount<'info>,
+
+    /// Driver or asset operator ending the session
+    #[account(
+        constraint = authority.key() == session_account.driver
+            || authority.key() == asset_account.institutional_operator @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, used to verify the signed meter reading
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<EndChargingSession>,
+    energy_delivered_wh: u64,
+    reading_timestamp: i64,
+    device_key: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session_key = ctx.accounts.session_account.key();
+    let started_at = ctx.accounts.session_account.started_at;
+
+    // Verify the charger's signed meter reading
+    let reading = MeterReading {
+        session_account: session_key,
+        energy_delivered_wh,
+        timestamp: reading_timestamp,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &device_key,
+        &reading.try_to_vec()?,
+    )?;
+
+    // The device must meter the session's connector, and its readings must be
+    // taken during the session and move forward in time. A connector hosts one
+    // session at a time, so each device's readings settle in order.
+    let connector_id = ctx.accounts.session_account.connector_id;
+    let device = ctx
+        .accounts
+        .station_account
+        .find_device_mut(&device_key)
+        .ok_or(MarketplaceError::ChargerDeviceNotRegistered)?;
+    require!(
+        device.connector_id == connector_id,
+        MarketplaceError::ChargerDeviceConnectorMismatch
+    );
+    require!(
+        reading_timestamp >= started_at
+            && reading_timestamp <= clock.unix_timestamp
+            && reading_timestamp > device.last_reading_at,
+        MarketplaceError::StaleMeterReading
+    );
+    device.last_reading_at = reading_timestamp;
+
+    let session = &ctx.accounts.session_account;
+    let station = &ctx.accounts.station_account;
+
+    let duration = reading_timestamp - session.started_at;
+    require!(
+        station.is_plausible_energy(energy_delivered_wh, duration),
+        MarketplaceError::InvalidEnergyReading
+    );
+
+    // Charge energy and time at the station's rates, capped by the escrowed maximum
+    let charge_usdc = station
+        .session_cost(energy_delivered_wh, duration)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?
+        .min(session.max_amount_usdc);
+    let refund_usdc = ctx.accounts.escrow_token_account.amount.saturating_sub(charge_usdc);
+
+    let station_key = session.station_account;
+    let driver_key = session.driver;
+    let started_at_bytes = session.started_at.to_le_bytes();
+    let seeds = &[
+        b"charging_session",
+        station_key.as_ref(),
+        driver_key.as_ref(),
+        started_at_bytes.as_ref(),
+        &[session.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Pay the station's revenue vault
+    if charge_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: session.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, charge_usdc)?;
+
+        ctx.accounts
+            .revenue_vault
+            .record_inflow(charge_usdc, clock.unix_timestamp)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    }
+
+    // Refund the rest to the driver
+    if refund_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.driver_payment_account.to_account_info(),
+                authority: session.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund_usdc)?;
+    }
+
+    // Close the escrow and return its rent to the driver
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.driver.to_account_info(),
+            authority: session.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    // Free the connector
+    ctx.accounts
+        .station_account
+        .set_connector_in_use(connector_id, false);
+
+    let session = &mut ctx.accounts.session_account;
+    session.energy_delivered_wh = energy_delivered_wh;
+    session.metered_by = device_key;
+    session.amount_charged_usdc = charge_usdc;
+    session.ended_at = clock.unix_timestamp;
+    session.status = SessionStatus::Settled;
+
+    msg!(
+        "Charging session settled: {} Wh, {} charged, {} refunded",
+        energy_delivered_wh,
+        charge_usdc,
+        refund_usdc
+    );
+
+    Ok(())
+}
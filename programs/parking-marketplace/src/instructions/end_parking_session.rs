@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::pricing;
+
+#[derive(Accounts)]
+pub struct EndParkingSession<'info> {
+    /// Parking session
+    #[account(
+        mut,
+        constraint = session_account.asset_account == asset_account.key() @ MarketplaceError::InvalidSession,
+        constraint = session_account.status == SessionStatus::Active @ MarketplaceError::SessionNotActive,
+    )]
+    pub session_account: Account<'info, ParkingSession>,
+
+    /// Session escrow (closed at settlement)
+    #[account(
+        mut,
+        seeds = [b"session_escrow", session_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Parking asset (spot or lot)
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Pricing schedule for the asset
+    #[account(
+        seeds = [b"pricing_schedule", asset_account.key().as_ref()],
+        bump = pricing_schedule.bump,
+    )]
+    pub pricing_schedule: Account<'info, PricingSchedule>,
+
+    /// Asset revenue vault (receives the charge)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            session_account.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Driver's payment token account (receives the refund)
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == session_account.driver,
+        constraint = driver_payment_account.mint == session_account.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// Asset token account recorded at session start (required if the driver qualified)
+    #[account(
+        address = session_account.holder_token_account @ MarketplaceError::InvalidSession,
+        constraint = holder_token_account.owner == session_account.driver @ MarketplaceError::InvalidSession,
+    )]
+    pub holder_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Driver's subscription pass (optional, covers the session if active)
+    pub subscription_pass: Option<Account<'info, SubscriptionPass>>,
+
+    /// Offer the pass was issued from (required with the pass)
+    pub subscription_offer: Option<Account<'info, SubscriptionOffer>>,
+
+    /// Asset the offer was created for (required when the pass is used on
+    /// another asset of the same lot)
+    pub subscription_offer_asset: Option<Account<'info, ParkingAsset>>,
+
+    /// Driver's pass token account (optional, proves ownership of the pass)
+    pub pass_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Driver wallet, receives escrow rent; validated against the session
+    #[account(
+        mut,
+        address = session_account.driver @ MarketplaceError::InvalidSession,
+    )]
+    pub driver: UncheckedAccount<'info>,
+
+    /// Driver or asset operator ending the session
+    #[account(
+        constraint = authority.key() == session_account.driver
+            || authority.key() == asset_account.institutional_operator @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub authority: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<EndParkingSession>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.session_account;
+
+    // Charge duration at the asset's rates, capped by the escrowed maximum
+    let duration = clock.unix_timestamp.saturating_sub(session.started_at);
+    let charge_usdc = if duration <= 0 {
+        0
+    } else if duration > pricing::MAX_QUOTE_SECONDS {
+        session.max_amount_usdc
+    } else {
+        pricing::quote(
+            &ctx.accounts.pricing_schedule.rates,
+            session.started_at,
+            clock.unix_timestamp,
+        )
+        .ok_or(MarketplaceError::ArithmeticOverflow)?
+        .min(session.max_amount_usdc)
+    };
+
+    // Active subscription passes park for free; otherwise holders get their discount
+    let asset = &ctx.accounts.asset_account;
+    let covered_by_pass = match (
+        &ctx.accounts.subscription_pass,
+        &ctx.accounts.subscription_offer,
+        &ctx.accounts.pass_token_account,
+    ) {
+        (Some(pass), Some(offer), Some(pass_token_account)) => {
+            pass.is_held_by(pass_token_account, &session.driver)
+                && pass.covers(&offer.key(), session.started_at, clock.unix_timestamp)
+                && offer.applies_to(
+                    &asset.key(),
+                    asset,
+                    ctx.accounts.subscription_offer_asset.as_ref(),
+                )
+        }
+        _ => false,
+    };
+    // The discount only applies if the qualifying balance is still held. This
+    // is checked at start and end only, not for the whole session
+    let holder_balance = if session.has_holder_discount() {
+        let holder_token_account = ctx
+            .accounts
+            .holder_token_account
+            .as_ref()
+            .ok_or(MarketplaceError::HolderAccountRequired)?;
+        holder_token_account.amount.min(session.qualifying_balance)
+    } else {
+        0
+    };
+    let charge_usdc = if covered_by_pass {
+        0
+    } else {
+        ctx.accounts
+            .asset_account
+            .holder_price(charge_usdc, holder_balance)
+    };
+    let refund_usdc = ctx.accounts.escrow_token_account.amount.saturating_sub(charge_usdc);
+
+    let asset_key = session.asset_account;
+    let driver_key = session.driver;
+    let started_at_bytes = session.started_at.to_le_bytes();
+    let seeds = &[
+        b"parking_session",
+        asset_key.as_ref(),
+        driver_key.as_ref(),
+        started_at_bytes.as_ref(),
+        &[session.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Pay the asset's revenue vault
+    if charge_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: session.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, charge_usdc)?;
+
+        ctx.accounts
+            .revenue_vault
+            .record_inflow(charge_usdc, clock.unix_timestamp)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    }
+
+    // Refund the rest to the driver
+    if refund_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.driver_payment_account.to_account_info(),
+                authority: session.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund_usdc)?;
+    }
+
+    // Close the escrow and return its rent to the driver
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.driver.to_account_info(),
+            authority: session.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    let session = &mut ctx.accounts.session_account;
+    session.amount_charged_usdc = charge_usdc;
+    session.ended_at = clock.unix_timestamp;
+    session.status = SessionStatus::Settled;
+
+    msg!(
+        "Parking session settled: {} charged, {} refunded",
+        charge_usdc,
+        refund_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ExecuteAction<'info> {
+    /// Pending action (closed, rent returned to the proposer)
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            b"pending_action",
+            operator_multisig.key().as_ref(),
+            pending_action.index.to_le_bytes().as_ref()
+        ],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Operator multisig
+    #[account(
+        seeds = [b"operator_multisig", operator_multisig.create_key.as_ref()],
+        bump = operator_multisig.bump,
+        constraint = operator_multisig.is_signer(&executor.key()) @ MarketplaceError::NotMultisigSigner,
+    )]
+    pub operator_multisig: Account<'info, OperatorMultisig>,
+
+    /// CHECK: Multisig signer PDA, signs the executed instruction
+    #[account(
+        mut,
+        seeds = [b"multisig_signer", operator_multisig.key().as_ref()],
+        bump = operator_multisig.signer_bump,
+    )]
+    pub multisig_signer: UncheckedAccount<'info>,
+
+    /// CHECK: Proposer, receives the pending action's rent
+    #[account(
+        mut,
+        address = pending_action.proposer,
+    )]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Multisig signer executing the action
+    pub executor: Signer<'info>,
+
+    /// CHECK: This program, invoked with the stored instruction
+    #[account(address = crate::ID)]
+    pub marketplace_program: UncheckedAccount<'info>,
+    // remaining_accounts: the stored instruction's accounts, in order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteAction<'info>>,
+) -> Result<()> {
+    let action = &ctx.accounts.pending_action;
+    let multisig = &ctx.accounts.operator_multisig;
+    let clock = Clock::get()?;
+
+    require!(
+        !action.is_expired(clock.unix_timestamp),
+        MarketplaceError::ActionExpired
+    );
+
+    // Only approvals from current signers count
+    let approvals = action
+        .approvals
+        .iter()
+        .filter(|approver| multisig.is_signer(approver))
+        .count();
+    require!(
+        approvals >= multisig.threshold as usize,
+        MarketplaceError::ApprovalThresholdNotMet
+    );
+
+    require!(
+        ctx.remaining_accounts.len() == action.accounts.len()
+            && ctx
+                .remaining_accounts
+                .iter()
+                .zip(action.accounts.iter())
+                .all(|(info, meta)| info.key() == meta.pubkey),
+        MarketplaceError::InvalidPendingAction
+    );
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: action
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: action.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.marketplace_program.to_account_info());
+
+    let multisig_key = multisig.key();
+    let seeds = &[
+        b"multisig_signer",
+        multisig_key.as_ref(),
+        &[multisig.signer_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    invoke_signed(&instruction, &account_infos, signer)?;
+
+    msg!(
+        "Action {} executed with {} approvals",
+        action.index,
+        approvals
+    );
+
+    Ok(())
+}
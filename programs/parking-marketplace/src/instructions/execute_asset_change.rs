@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ExecuteAssetChange<'info> {
+    /// Timelocked change (closed, rent returned to operator)
+    #[account(
+        mut,
+        close = operator,
+        constraint = timelocked_change.asset_account == asset_account.key() @ MarketplaceError::InvalidTimelockedChange,
+        // Changes queued before an operator transfer lapse with it
+        constraint = timelocked_change.operator == operator.key() @ MarketplaceError::ChangeScheduledByPreviousOperator,
+    )]
+    pub timelocked_change: Account<'info, TimelockedChange>,
+
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (oracle registry)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Pricing schedule (required for pricing changes)
+    #[account(
+        mut,
+        seeds = [b"pricing_schedule", asset_account.key().as_ref()],
+        bump = pricing_schedule.bump,
+    )]
+    pub pricing_schedule: Option<Account<'info, PricingSchedule>>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ExecuteAssetChange>,
+) -> Result<()> {
+    let timelocked = &ctx.accounts.timelocked_change;
+    let asset = &mut ctx.accounts.asset_account;
+    let clock = Clock::get()?;
+
+    require!(
+        timelocked.is_executable(clock.unix_timestamp),
+        MarketplaceError::TimelockNotElapsed
+    );
+
+    // The asset may have changed since scheduling
+    require!(
+        timelocked.change.is_valid_for(asset, &ctx.accounts.protocol_config),
+        MarketplaceError::InvalidTimelockedChange
+    );
+
+    timelocked.change.apply(asset);
+
+    if let AssetChange::PricingRates(rates) = timelocked.change {
+        let schedule = ctx
+            .accounts
+            .pricing_schedule
+            .as_mut()
+            .ok_or(MarketplaceError::InvalidTimelockedChange)?;
+        schedule.rates = rates;
+        schedule.updated_at = clock.unix_timestamp;
+    }
+
+    msg!(
+        "Scheduled asset change executed for {}",
+        asset.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::verification::verify_balance_snapshot;
+
+#[derive(Accounts)]
+pub struct ExercisePreemption<'info> {
+    /// Issuance account
+    #[account(
+        mut,
+        constraint = issuance.asset_account == asset_account.key() @ MarketplaceError::InvalidIssuance,
+    )]
+    pub issuance: Account<'info, Issuance>,
+
+    /// Pre-emption allocation PDA (one exercise per holder)
+    #[account(
+        init,
+        payer = subscriber,
+        space = PreemptionAllocation::LEN,
+        seeds = [
+            b"preemption_allocation",
+            issuance.key().as_ref(),
+            subscriber.key().as_ref()
+        ],
+        bump
+    )]
+    pub allocation: Account<'info, PreemptionAllocation>,
+
+    /// Parking asset (mint authority)
+    #[account(
+        mut,
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.asset_type != AssetType::ParkingLotBundle @ MarketplaceError::InvalidAssetType,
+        constraint = !asset_account.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Asset token mint
+    #[account(
+        mut,
+        address = asset_account.asset_token_mint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Subscriber's asset token account (receives the new tokens)
+    #[account(
+        mut,
+        constraint = subscriber_token_account.owner == subscriber.key(),
+        constraint = subscriber_token_account.mint == token_mint.key(),
+    )]
+    pub subscriber_token_account: Account<'info, TokenAccount>,
+
+    /// Subscriber's payment token account
+    #[account(
+        mut,
+        constraint = subscriber_payment_account.owner == subscriber.key(),
+        constraint = subscriber_payment_account.mint == issuance.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub subscriber_payment_account: Account<'info, TokenAccount>,
+
+    /// Operator's payment token account (receives the proceeds)
+    #[account(
+        mut,
+        constraint = operator_payment_account.owner == asset_account.institutional_operator @ MarketplaceError::UnauthorizedOperator,
+        constraint = operator_payment_account.mint == issuance.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub operator_payment_account: Account<'info, TokenAccount>,
+
+    /// Existing holder exercising pre-emption rights
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ExercisePreemption>,
+    amount: u64,
+    snapshot_balance: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let issuance = &mut ctx.accounts.issuance;
+    let clock = Clock::get()?;
+
+    require!(
+        issuance.is_preemption_open(clock.unix_timestamp),
+        MarketplaceError::IssuanceNotOpen
+    );
+
+    // Holders may buy their pro rata share of the issuance, sized from the
+    // balance snapshot taken at announcement so tokens passed between
+    // wallets during the window cannot claim the same share twice
+    verify_balance_snapshot(
+        &issuance.holder_snapshot_root,
+        &ctx.accounts.subscriber.key(),
+        snapshot_balance,
+        &proof,
+    )?;
+    let allocation_limit = issuance.preemption_allocation(snapshot_balance);
+    require!(
+        amount <= allocation_limit,
+        MarketplaceError::PreemptionAllocationExceeded
+    );
+    require!(
+        amount <= issuance.remaining_tokens(),
+        MarketplaceError::InsufficientBalance
+    );
+    require!(
+        ctx.accounts.asset_account.can_issue(amount),
+        MarketplaceError::SupplyCapExceeded
+    );
+
+    let payment_usdc = issuance
+        .price_per_token_usdc
+        .checked_mul(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Pay the operator
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.subscriber_payment_account.to_account_info(),
+            to: ctx.accounts.operator_payment_account.to_account_info(),
+            authority: ctx.accounts.subscriber.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, payment_usdc)?;
+
+    // Mint the new tokens
+    let asset = &ctx.accounts.asset_account;
+    let lot_id_bytes = asset.parking_lot_id.to_le_bytes();
+    let seeds = &[
+        b"parking_asset",
+        lot_id_bytes.as_ref(),
+        asset.spot_number.as_bytes(),
+        &[asset.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.subscriber_token_account.to_account_info(),
+            authority: asset.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, amount)?;
+
+    issuance.tokens_sold = issuance
+        .tokens_sold
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let allocation = &mut ctx.accounts.allocation;
+    allocation.issuance = issuance.key();
+    allocation.holder = ctx.accounts.subscriber.key();
+    allocation.amount = amount;
+    allocation.exercised_at = clock.unix_timestamp;
+    allocation.bump = ctx.bumps.allocation;
+
+    let asset = &mut ctx.accounts.asset_account;
+    asset.total_supply = asset
+        .total_supply
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    asset.circulating_supply = asset
+        .circulating_supply
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Pre-emption exercised: {} tokens for {}",
+        amount,
+        payment_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ExpireChargingSession<'info> {
+    /// Charging session
+    #[account(
+        mut,
+        constraint = session_account.station_account == station_account.key() @ MarketplaceError::InvalidSession,
+        constraint = session_account.status == SessionStatus::Active @ MarketplaceError::SessionNotActive,
+    )]
+    pub session_account: Account<'info, ChargingSession>,
+
+    /// Session escrow (refunded and closed)
+    #[account(
+        mut,
+        seeds = [b"session_escrow", session_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Charging station
+    #[account(mut)]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Driver's payment token account (receives the refund)
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == session_account.driver,
+        constraint = driver_payment_account.mint == session_account.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Driver wallet, receives escrow rent; validated against the session
+    #[account(
+        mut,
+        address = session_account.driver @ MarketplaceError::InvalidSession,
+    )]
+    pub driver: UncheckedAccount<'info>,
+
+    /// Anyone may expire a timed-out session; funds only go to the driver
+    pub authority: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ExpireChargingSession>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.session_account;
+
+    require!(
+        session.is_expired(clock.unix_timestamp),
+        MarketplaceError::SessionNotExpired
+    );
+
+    let refund_usdc = ctx.accounts.escrow_token_account.amount;
+
+    let station_key = session.station_account;
+    let driver_key = session.driver;
+    let started_at_bytes = session.started_at.to_le_bytes();
+    let seeds = &[
+        b"charging_session",
+        station_key.as_ref(),
+        driver_key.as_ref(),
+        started_at_bytes.as_ref(),
+        &[session.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // No signed reading arrived in time: refund the whole escrow
+    if refund_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.driver_payment_account.to_account_info(),
+                authority: session.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund_usdc)?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.driver.to_account_info(),
+            authority: session.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    // Free the connector
+    let connector_id = session.connector_id;
+    ctx.accounts
+        .station_account
+        .set_connector_in_use(connector_id, false);
+
+    let session = &mut ctx.accounts.session_account;
+    session.ended_at = clock.unix_timestamp;
+    session.status = SessionStatus::Expired;
+
+    msg!(
+        "Charging session expired: {} refunded",
+        refund_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct FailDistribution<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Distribution vault (holder funds are returned from here)
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Asset revenue vault for the distribution's payout mint
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            distribution_account.payout_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator's payout token account (returns the operator's share)
+    #[account(
+        mut,
+        constraint = operator_payout_account.owner == operator.key(),
+        constraint = operator_payout_account.mint == distribution_account.payout_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payout_account: Account<'info, TokenAccount>,
+
+    /// Operator marking the failure
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<FailDistribution>,
+    reason: DistributionFailureReason,
+) -> Result<()> {
+    require!(
+        reason != DistributionFailureReason::None,
+        MarketplaceError::InvalidFailureReason
+    );
+
+    let distribution = &mut ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.distribution_status != DistributionStatus::Completed,
+        MarketplaceError::DistributionAlreadyCompleted
+    );
+    // Once claims are open the operator can no longer pull the distribution
+    require!(
+        distribution.distribution_status == DistributionStatus::Pending,
+        MarketplaceError::InvalidDistributionStatus
+    );
+
+    distribution.distribution_status = DistributionStatus::Failed;
+    distribution.failure_reason = reason;
+    distribution.completed_at = clock.unix_timestamp;
+
+    // Release the period so a corrected report can be submitted
+    let asset = &mut ctx.accounts.asset_account;
+    if asset.last_distributed_period_end == distribution.period_end {
+        asset.last_distributed_period_end = distribution.previous_period_end;
+    }
+
+    // Return holder funds and the operator's share to the revenue vault so
+    // the corrected report redistributes all of it; only the operator's own
+    // top-ups stay behind to be swept
+    let returned_usdc = distribution.holder_funds_in(ctx.accounts.distribution_vault.amount);
+    if returned_usdc > 0 {
+        let asset_key = distribution.asset_account;
+        let created_at_bytes = distribution.created_at.to_le_bytes();
+        let seeds = &[
+            b"revenue_distribution",
+            asset_key.as_ref(),
+            created_at_bytes.as_ref(),
+            &[distribution.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: distribution.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, returned_usdc)?;
+        distribution.vault_funded_usdc -= returned_usdc;
+    }
+
+    let operator_share_usdc = distribution.operator_share_usdc;
+    if operator_share_usdc > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.operator_payout_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.operator.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, operator_share_usdc)?;
+        distribution.operator_share_usdc = 0;
+    }
+
+    let revenue_vault = &mut ctx.accounts.revenue_vault;
+    revenue_vault.distributed_inflows = revenue_vault
+        .distributed_inflows
+        .saturating_sub(returned_usdc + operator_share_usdc);
+
+    msg!(
+        "Revenue distribution failed: {} ({} returned to the revenue vault, {} from the operator)",
+        distribution.key(),
+        returned_usdc,
+        operator_share_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct FinalizeDistribution<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator finalizing the distribution
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<FinalizeDistribution>,
+) -> Result<()> {
+    let distribution = &mut ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.distribution_status != DistributionStatus::Completed,
+        MarketplaceError::DistributionAlreadyCompleted
+    );
+    require!(
+        distribution.distribution_status == DistributionStatus::Processing,
+        MarketplaceError::InvalidDistributionStatus
+    );
+
+    // All claims paid, or the claim window has closed
+    require!(
+        distribution.is_fully_distributed()
+            || distribution.is_claim_window_closed(clock.unix_timestamp),
+        MarketplaceError::ClaimWindowOpen
+    );
+
+    distribution.distribution_status = DistributionStatus::Completed;
+    distribution.completed_at = clock.unix_timestamp;
+
+    msg!(
+        "Revenue distribution completed: {} distributed in mint {}",
+        distribution.total_distributed_usdc,
+        distribution.payout_mint
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct FinalizeProceeds<'info> {
+    /// Decommission account
+    #[account(
+        mut,
+        seeds = [b"decommission", decommission.asset_account.as_ref()],
+        bump = decommission.bump,
+    )]
+    pub decommission: Account<'info, Decommission>,
+
+    /// Parking asset being wound down
+    #[account(
+        address = decommission.asset_account,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Vault holding the sale proceeds
+    #[account(
+        seeds = [b"decommission_vault", decommission.key().as_ref()],
+        bump,
+    )]
+    pub decommission_vault: Account<'info, TokenAccount>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<FinalizeProceeds>,
+) -> Result<()> {
+    let decommission = &mut ctx.accounts.decommission;
+    let clock = Clock::get()?;
+
+    require!(
+        !decommission.proceeds_finalized,
+        MarketplaceError::ProceedsFinalized
+    );
+    require!(
+        decommission.proceeds_usdc > 0 && ctx.accounts.decommission_vault.amount > 0,
+        MarketplaceError::InvalidDecommission
+    );
+
+    // The window runs from here so a slow sale cannot shorten it
+    decommission.proceeds_finalized = true;
+    decommission.redemption_deadline = clock
+        .unix_timestamp
+        .checked_add(decommission.redemption_window_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Sale proceeds finalized at {}, final redemption open until {}",
+        decommission.proceeds_usdc,
+        decommission.redemption_deadline
+    );
+
+    Ok(())
+}
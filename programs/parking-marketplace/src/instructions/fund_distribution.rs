@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct FundDistribution<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Distribution vault (holds payouts in the distribution's payout mint)
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Operator's payout token account (source of funds)
+    #[account(
+        mut,
+        constraint = operator_payout_account.owner == operator.key(),
+        constraint = operator_payout_account.mint == distribution_account.payout_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payout_account: Account<'info, TokenAccount>,
+
+    /// Operator funding the distribution
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<FundDistribution>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let distribution = &mut ctx.accounts.distribution_account;
+
+    require!(
+        distribution.distribution_status != DistributionStatus::Completed,
+        MarketplaceError::DistributionAlreadyCompleted
+    );
+    require!(
+        distribution.distribution_status == DistributionStatus::Pending
+            || distribution.distribution_status == DistributionStatus::Processing,
+        MarketplaceError::InvalidDistributionStatus
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.operator_payout_account.to_account_info(),
+            to: ctx.accounts.distribution_vault.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    // Tracked separately so only the operator's own top-ups can be swept back to it
+    distribution.operator_funded_usdc = distribution
+        .operator_funded_usdc
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Distribution funded: {} tokens of mint {}",
+        amount,
+        distribution.payout_mint
+    );
+
+    Ok(())
+}
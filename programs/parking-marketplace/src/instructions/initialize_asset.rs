@@ -52,18 +52,28 @@ pub struct InitializeAsset<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<InitializeAsset>,
     parking_lot_id: u64,
     spot_number: String,
     asset_type: AssetType,
     total_supply: u64,
+    max_supply: u64,
     estimated_value_usdc: u64,
     annual_revenue_usdc: u64,
     revenue_share_percentage: u16,
+    cost_caps: OperatingCostCaps,
 ) -> Result<()> {
     // Validate inputs
     require!(total_supply > 0, MarketplaceError::InvalidTokenAmount);
+    require!(max_supply >= total_supply, MarketplaceError::InvalidSupplyCap);
+    // Bundles are backed by component tokens and must go through
+    // initialize_bundle
+    require!(
+        asset_type != AssetType::ParkingLotBundle,
+        MarketplaceError::InvalidAssetType
+    );
     require!(
         spot_number.len() <= 32,
         MarketplaceError::InvalidSpotNumber
@@ -72,6 +82,7 @@ pub fn handler(
         revenue_share_percentage <= 10000,
         MarketplaceError::InvalidRevenueSharePercentage
     );
+    require!(cost_caps.is_valid(), MarketplaceError::InvalidCostCaps);
 
     let asset = &mut ctx.accounts.asset_account;
     let clock = Clock::get()?;
@@ -91,6 +102,14 @@ pub fn handler(
     asset.is_active = true;
     asset.is_tradeable = true;
     asset.created_at = clock.unix_timestamp;
+    asset.last_distributed_period_end = 0;
+    asset.revenue_oracle = Pubkey::default();
+    asset.cost_caps = cost_caps;
+    asset.holder_privilege = HolderPrivilege::default();
+    asset.max_supply = max_supply; // Raised only through a timelocked change
+    asset.authorized_issuance = 0;
+    asset.paused = PauseFlags::default();
+    asset.revenue_report_nonce = 0;
     asset.bump = ctx.bumps.asset_account;
 
     // Mint initial supply to operator
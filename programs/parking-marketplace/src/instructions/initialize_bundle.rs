@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+#[instruction(parking_lot_id: u64, spot_number: String)]
+pub struct InitializeBundle<'info> {
+    /// Bundle asset account PDA (ParkingLotBundle type)
+    #[account(
+        init,
+        payer = creator,
+        space = ParkingAsset::LEN,
+        seeds = [
+            b"parking_asset",
+            parking_lot_id.to_le_bytes().as_ref(),
+            spot_number.as_bytes()
+        ],
+        bump
+    )]
+    pub bundle_asset: Account<'info, ParkingAsset>,
+
+    /// Bundle composition PDA
+    #[account(
+        init,
+        payer = creator,
+        space = AssetBundle::LEN,
+        seeds = [b"asset_bundle", bundle_asset.key().as_ref()],
+        bump
+    )]
+    pub asset_bundle: Account<'info, AssetBundle>,
+
+    /// SPL token mint for the bundle tokens
+    #[account(
+        init,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = bundle_asset,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Bundle creator (operator of the bundle asset)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeBundle>,
+    parking_lot_id: u64,
+    spot_number: String,
+) -> Result<()> {
+    require!(
+        spot_number.len() <= 32,
+        MarketplaceError::InvalidSpotNumber
+    );
+
+    let asset = &mut ctx.accounts.bundle_asset;
+    let clock = Clock::get()?;
+
+    // Bundle tokens are only minted against deposited constituents
+    asset.asset_token_mint = ctx.accounts.token_mint.key();
+    asset.asset_type = AssetType::ParkingLotBundle;
+    asset.parking_lot_id = parking_lot_id;
+    asset.spot_number = spot_number;
+    asset.total_supply = 0;
+    asset.circulating_supply = 0;
+    asset.estimated_value_usdc = 0;
+    asset.annual_revenue_usdc = 0;
+    asset.revenue_share_percentage = 10000;
+    asset.institutional_operator = ctx.accounts.creator.key();
+    asset.compliance_status = ComplianceStatus::Pending;
+    asset.is_active = true;
+    asset.is_tradeable = true;
+    asset.created_at = clock.unix_timestamp;
+    asset.last_distributed_period_end = 0;
+    asset.revenue_oracle = Pubkey::default();
+    asset.cost_caps = OperatingCostCaps::default();
+    asset.holder_privilege = HolderPrivilege::default();
+    asset.max_supply = 0; // Minted only by bundle_tokens against deposited constituents
+    asset.authorized_issuance = 0;
+    asset.paused = PauseFlags::default();
+    asset.revenue_report_nonce = 0;
+    asset.bump = ctx.bumps.bundle_asset;
+
+    let bundle = &mut ctx.accounts.asset_bundle;
+    bundle.bundle_asset = asset.key();
+    bundle.components = Vec::new();
+    bundle.created_at = clock.unix_timestamp;
+    bundle.bump = ctx.bumps.asset_bundle;
+
+    msg!(
+        "Parking lot bundle initialized: {} at lot {}",
+        asset.spot_number,
+        parking_lot_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializeChargingStation<'info> {
+    /// Charging station PDA
+    #[account(
+        init,
+        payer = operator,
+        space = ChargingStation::LEN,
+        seeds = [b"charging_station", asset_account.key().as_ref()],
+        bump
+    )]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Parking asset (must be a charging station)
+    #[account(
+        constraint = asset_account.asset_type == AssetType::ChargingStation @ MarketplaceError::InvalidAssetType,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeChargingStation>,
+    station_id: u64,
+    charger_type: ChargerType,
+    total_connectors: u8,
+    max_power_kw: u32,
+    rates: ChargingRates,
+) -> Result<()> {
+    // Validate inputs
+    require!(
+        total_connectors > 0 && total_connectors <= ChargingStation::MAX_CONNECTORS,
+        MarketplaceError::InvalidStationParameters
+    );
+    require!(max_power_kw > 0, MarketplaceError::InvalidStationParameters);
+    require!(rates.price_per_kwh_usdc > 0, MarketplaceError::InvalidPrice);
+
+    let station = &mut ctx.accounts.station_account;
+    let clock = Clock::get()?;
+
+    station.asset_account = ctx.accounts.asset_account.key();
+    station.station_id = station_id;
+    station.charger_type = charger_type;
+    station.total_connectors = total_connectors;
+    station.connectors_in_use = 0;
+    station.max_power_kw = max_power_kw;
+    station.rates = rates;
+    station.charger_devices = Vec::new();
+    station.created_at = clock.unix_timestamp;
+    station.bump = ctx.bumps.station_account;
+
+    msg!(
+        "Charging station {} initialized: {} connectors, {} kW, {} per kWh",
+        station_id,
+        total_connectors,
+        max_power_kw,
+        rates.price_per_kwh_usdc
+    );
+
+    Ok(())
+}
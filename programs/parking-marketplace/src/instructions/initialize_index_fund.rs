@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializeIndexFund<'info> {
+    /// Index fund PDA
+    #[account(
+        init,
+        payer = manager,
+        space = IndexFund::LEN,
+        seeds = [b"index_fund", index_mint.key().as_ref()],
+        bump
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// SPL token mint for the index tokens
+    #[account(
+        init,
+        payer = manager,
+        mint::decimals = IndexFund::INDEX_DECIMALS,
+        mint::authority = index_fund,
+    )]
+    pub index_mint: Account<'info, Mint>,
+
+    /// Index manager
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeIndexFund>,
+    band_bps: u16,
+    min_rebalance_interval: i64,
+) -> Result<()> {
+    require!(
+        band_bps > 0 && band_bps <= 10000,
+        MarketplaceError::InvalidIndexWeights
+    );
+    require!(
+        min_rebalance_interval >= 0,
+        MarketplaceError::InvalidIndexWeights
+    );
+
+    let index_fund = &mut ctx.accounts.index_fund;
+    let clock = Clock::get()?;
+
+    index_fund.manager = ctx.accounts.manager.key();
+    index_fund.index_mint = ctx.accounts.index_mint.key();
+    index_fund.positions = Vec::new();
+    index_fund.revenue_vaults = Vec::new();
+    index_fund.band_bps = band_bps;
+    index_fund.min_rebalance_interval = min_rebalance_interval;
+    index_fund.last_rebalanced_at = 0;
+    index_fund.created_at = clock.unix_timestamp;
+    index_fund.bump = ctx.bumps.index_fund;
+
+    msg!(
+        "Index fund initialized: mint {}, band {} bps",
+        index_fund.index_mint,
+        band_bps
+    );
+
+    Ok(())
+}
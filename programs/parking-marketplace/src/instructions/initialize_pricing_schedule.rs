@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializePricingSchedule<'info> {
+    /// Pricing schedule PDA
+    #[account(
+        init,
+        payer = operator,
+        space = PricingSchedule::LEN,
+        seeds = [b"pricing_schedule", asset_account.key().as_ref()],
+        bump
+    )]
+    pub pricing_schedule: Account<'info, PricingSchedule>,
+
+    /// Parking asset (spot or lot)
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializePricingSchedule>,
+    rates: PricingRates,
+) -> Result<()> {
+    require!(rates.is_valid(), MarketplaceError::InvalidPricingSchedule);
+
+    let schedule = &mut ctx.accounts.pricing_schedule;
+    let clock = Clock::get()?;
+
+    schedule.asset_account = ctx.accounts.asset_account.key();
+    schedule.rates = rates;
+    schedule.updated_at = clock.unix_timestamp;
+    schedule.bump = ctx.bumps.pricing_schedule;
+
+    msg!(
+        "Pricing schedule initialized for asset {}: {} per hour",
+        schedule.asset_account,
+        rates.price_per_hour
+    );
+
+    Ok(())
+}
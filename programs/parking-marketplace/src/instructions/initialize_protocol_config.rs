@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    /// Protocol config PDA
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeProtocolConfig>,
+    accepted_payout_mints: Vec<Pubkey>,
+    reserve_fund: Pubkey,
+) -> Result<()> {
+    require!(
+        accepted_payout_mints.len() <= ProtocolConfig::MAX_PAYOUT_MINTS,
+        MarketplaceError::TooManyPayoutMints
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.accepted_payout_mints = accepted_payout_mints;
+    config.reserve_fund = reserve_fund;
+    config.bump = ctx.bumps.protocol_config;
+
+    msg!(
+        "Protocol config initialized with {} payout mints",
+        config.accepted_payout_mints.len()
+    );
+
+    Ok(())
+}
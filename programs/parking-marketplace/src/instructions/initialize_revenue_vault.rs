@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializeRevenueVault<'info> {
+    /// Revenue vault PDA
+    #[account(
+        init,
+        payer = operator,
+        space = RevenueVault::LEN,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        init,
+        payer = operator,
+        seeds = [b"revenue_vault_tokens", revenue_vault.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = revenue_vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Payment token mint collected by the vault
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// Protocol config (payout mint allowlist)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeRevenueVault>,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.revenue_vault;
+
+    vault.asset_account = ctx.accounts.asset_account.key();
+    vault.mint = ctx.accounts.mint.key();
+    vault.token_account = ctx.accounts.vault_token_account.key();
+    vault.cumulative_inflows = 0;
+    vault.distributed_inflows = 0;
+    vault.last_inflow_at = 0;
+    vault.bump = ctx.bumps.revenue_vault;
+
+    msg!(
+        "Revenue vault initialized for asset {} in mint {}",
+        vault.asset_account,
+        vault.mint
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct InitializeSpotCalendar<'info> {
+    /// Spot calendar PDA
+    #[account(
+        init,
+        payer = operator,
+        space = SpotCalendar::LEN,
+        seeds = [b"spot_calendar", asset_account.key().as_ref()],
+        bump
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Parking asset (must be a single spot)
+    #[account(
+        constraint = asset_account.asset_type == AssetType::SingleSpot @ MarketplaceError::InvalidAssetType,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeSpotCalendar>,
+) -> Result<()> {
+    let calendar = &mut ctx.accounts.spot_calendar;
+
+    calendar.asset_account = ctx.accounts.asset_account.key();
+    calendar.bookings = Vec::new();
+    calendar.resale_policy = ResalePolicy::default();
+    calendar.bump = ctx.bumps.spot_calendar;
+
+    msg!(
+        "Spot calendar initialized for asset {}",
+        calendar.asset_account
+    );
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::Mint;
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::verification::verify_ed25519_signature;
+
+#[derive(Accounts)]
+pub struct IssueAdditionalTokens<'info> {
+    /// Issuance account PDA
+    #[account(
+        init,
+        payer = operator,
+        space = Issuance::LEN,
+        seeds = [
+            b"issuance",
+            asset_account.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub issuance: Account<'info, Issuance>,
+
+    /// Payment token mint
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payment_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Protocol config (payment mint allowlist, pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.asset_type != AssetType::ParkingLotBundle @ MarketplaceError::InvalidAssetType,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, used to verify the signed holder snapshot
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<IssueAdditionalTokens>,
+    amount: u64,
+    price_per_token_usdc: u64,
+    preemption_seconds: i64,
+    sale_seconds: i64,
+    holder_snapshot: HolderSnapshot,
+) -> Result<()> {
+    let asset = &mut ctx.accounts.asset_account;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+    require!(
+        price_per_token_usdc > 0 && preemption_seconds >= 0 && sale_seconds > 0,
+        MarketplaceError::InvalidIssuance
+    );
+    require!(
+        asset.can_issue(amount),
+        MarketplaceError::SupplyCapExceeded
+    );
+    require!(
+        amount <= asset.authorized_issuance,
+        MarketplaceError::ChangeRequiresTimelock
+    );
+
+    // Pre-emption rights are paid out against the holder snapshot, so it
+    // must be attested by the asset's oracle rather than taken on the
+    // operator's word
+    let snapshot_supply = if preemption_seconds > 0 {
+        require!(
+            holder_snapshot.is_valid(),
+            MarketplaceError::MissingSnapshotRoot
+        );
+        require!(
+            asset.requires_revenue_attestation(),
+            MarketplaceError::SnapshotAttestationRequired
+        );
+        require!(
+            ctx.accounts.protocol_config.is_revenue_oracle(&asset.revenue_oracle),
+            MarketplaceError::RevenueOracleNotRegistered
+        );
+
+        let report = PreemptionSnapshotReport {
+            asset_account: asset.key(),
+            nonce: asset.revenue_report_nonce,
+            holder_snapshot,
+        };
+        verify_ed25519_signature(
+            &ctx.accounts.instructions_sysvar.to_account_info(),
+            &asset.revenue_oracle,
+            &report.try_to_vec()?,
+        )?;
+
+        asset.revenue_report_nonce = asset
+            .revenue_report_nonce
+            .checked_add(1)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+        holder_snapshot.total_supply
+    } else {
+        asset.circulating_supply
+    };
+
+    let preemption_ends_at = clock
+        .unix_timestamp
+        .checked_add(preemption_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let sale_ends_at = preemption_ends_at
+        .checked_add(sale_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let issuance = &mut ctx.accounts.issuance;
+    issuance.asset_account = asset.key();
+    issuance.amount = amount;
+    issuance.price_per_token_usdc = price_per_token_usdc;
+    issuance.payment_mint = ctx.accounts.payment_mint.key();
+    issuance.snapshot_supply = snapshot_supply;
+    issuance.holder_snapshot_root = holder_snapshot.root;
+    issuance.tokens_sold = 0;
+    issuance.preemption_ends_at = preemption_ends_at;
+    issuance.sale_ends_at = sale_ends_at;
+    issuance.created_at = clock.unix_timestamp;
+    issuance.bump = ctx.bumps.issuance;
+
+    // Consume the timelocked authorization
+    asset.authorized_issuance -= amount;
+
+    msg!(
+        "Issuance announced: {} tokens at {} per token, pre-emption until {}",
+        amount,
+        price_per_token_usdc,
+        preemption_ends_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ListReservation<'info> {
+    /// Listing account PDA
+    #[account(
+        init,
+        payer = seller,
+        space = MarketplaceListing::LEN,
+        seeds = [
+            b"marketplace_listing",
+            asset_account.key().as_ref(),
+            seller.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub listing_account: Account<'info, MarketplaceListing>,
+
+    /// Reservation to resell
+    #[account(
+        constraint = reservation.asset_account == asset_account.key() @ MarketplaceError::ReservationNotResellable,
+        constraint = reservation.driver == seller.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// Spot calendar (resale policy)
+    #[account(
+        seeds = [b"spot_calendar", asset_account.key().as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Parking asset the reservation is for
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Seller (current reservation holder)
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ListReservation>,
+    price_usdc: u64,
+    expires_in_seconds: i64,
+) -> Result<()> {
+    let reservation = &ctx.accounts.reservation;
+    let clock = Clock::get()?;
+
+    // Validate inputs
+    require!(price_usdc > 0, MarketplaceError::InvalidPrice);
+    require!(
+        reservation.is_resellable(clock.unix_timestamp),
+        MarketplaceError::ReservationNotResellable
+    );
+    require!(
+        ctx.accounts
+            .spot_calendar
+            .resale_policy
+            .permits(price_usdc, reservation.original_price_usdc),
+        MarketplaceError::ResalePriceCapExceeded
+    );
+
+    // Listing cannot outlive the reservation start
+    let expires_at = clock
+        .unix_timestamp
+        .checked_add(expires_in_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?
+        .min(reservation.start_time);
+
+    let listing = &mut ctx.accounts.listing_account;
+    listing.asset_account = ctx.accounts.asset_account.key();
+    listing.seller = ctx.accounts.seller.key();
+    listing.listing_type = ListingType::Sale;
+    listing.listing_kind = ListingKind::Reservation;
+    listing.reservation = reservation.key();
+    listing.token_amount = 1;
+    listing.price_per_token_usdc = price_usdc;
+    listing.total_price_usdc = price_usdc;
+    listing.payment_methods = vec![reservation.payment_mint];
+    listing.minimum_purchase_usdc = 0;
+    listing.kyb_required = false;
+    listing.status = ListingStatus::Active;
+    listing.created_at = clock.unix_timestamp;
+    listing.expires_at = expires_at;
+    listing.bump = ctx.bumps.listing_account;
+
+    msg!(
+        "Reservation {} listed for {}",
+        reservation.key(),
+        price_usdc
+    );
+
+    Ok(())
+}
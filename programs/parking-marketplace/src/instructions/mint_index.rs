@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct MintIndex<'info> {
+    /// Index fund (mint authority for index tokens)
+    #[account(
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Index token mint
+    #[account(
+        mut,
+        address = index_fund.index_mint,
+    )]
+    pub index_mint: Account<'info, Mint>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Depositor's index token account
+    #[account(
+        mut,
+        constraint = depositor_index_account.mint == index_mint.key(),
+    )]
+    pub depositor_index_account: Account<'info, TokenAccount>,
+
+    /// Depositor
+    pub depositor: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: for each basket vault in order (positions, then
+    // revenue vaults), [vault (mut), depositor token account for its mint (mut)]
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintIndex<'info>>,
+    index_amount: u64,
+) -> Result<()> {
+    require!(index_amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let index_fund = &ctx.accounts.index_fund;
+    let supply = ctx.accounts.index_mint.supply;
+    require!(supply > 0, MarketplaceError::IndexNotSeeded);
+    require!(
+        ctx.remaining_accounts.len() == index_fund.basket_len() * 2,
+        MarketplaceError::InvalidIndexAccounts
+    );
+
+    // Deposit a pro-rata slice of every vault, so minting never depends
+    // on estimated asset values and cannot shift the index's composition
+    for (vault_key, pair) in index_fund.basket_vaults().zip(ctx.remaining_accounts.chunks(2)) {
+        require!(
+            pair[0].key() == vault_key,
+            MarketplaceError::InvalidIndexAccounts
+        );
+        let vault = Account::<TokenAccount>::try_from(&pair[0])?;
+        let amount = IndexFund::basket_deposit(vault.amount, index_amount, supply)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+        if amount == 0 {
+            continue;
+        }
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: pair[1].clone(),
+                to: pair[0].clone(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, amount)?;
+    }
+
+    let index_mint_key = index_fund.index_mint;
+    let seeds = &[
+        b"index_fund",
+        index_mint_key.as_ref(),
+        &[index_fund.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.index_mint.to_account_info(),
+            to: ctx.accounts.depositor_index_account.to_account_info(),
+            authority: index_fund.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, index_amount)?;
+
+    msg!(
+        "Index minted: {} index tokens against a basket of {} positions",
+        index_amount,
+        index_fund.positions.len()
+    );
+
+    Ok(())
+}
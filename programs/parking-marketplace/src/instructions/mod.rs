@@ -4,6 +4,90 @@ pub mod buy_asset;
 pub mod distribute_revenue;
 pub mod update_compliance;
 pub mod cancel_listing;
+pub mod start_distribution;
+pub mod reconcile_distribution;
+pub mod finalize_distribution;
+pub mod fail_distribution;
+pub mod initialize_protocol_config;
+pub mod update_payout_mints;
+pub mod fund_distribution;
+pub mod claim_revenue;
+pub mod sweep_unclaimed;
+pub mod update_revenue_oracles;
+pub mod set_revenue_oracle;
+pub mod update_cost_caps;
+pub mod initialize_revenue_vault;
+pub mod deposit_revenue;
+pub mod initialize_spot_calendar;
+pub mod reserve_spot;
+pub mod initialize_pricing_schedule;
+pub mod start_parking_session;
+pub mod end_parking_session;
+pub mod set_holder_privilege;
+pub mod initialize_charging_station;
+pub mod start_charging_session;
+pub mod end_charging_session;
+pub mod register_charger_device;
+pub mod remove_charger_device;
+pub mod create_subscription_offer;
+pub mod purchase_subscription;
+pub mod renew_subscription;
+pub mod set_resale_policy;
+pub mod list_reservation;
+pub mod buy_reservation;
+pub mod initialize_bundle;
+pub mod add_bundle_component;
+pub mod bundle_tokens;
+pub mod unbundle_tokens;
+pub mod collect_bundle_revenue;
+pub mod initialize_index_fund;
+pub mod add_index_position;
+pub mod mint_index;
+pub mod redeem_index;
+pub mod rebalance_index;
+pub mod open_buyback;
+pub mod redeem_tokens;
+pub mod close_buyback;
+pub mod issue_additional_tokens;
+pub mod exercise_preemption;
+pub mod subscribe_issuance;
+pub mod set_compliance_authority;
+pub mod propose_operator_transfer;
+pub mod approve_operator_transfer;
+pub mod accept_operator_transfer;
+pub mod cancel_operator_transfer;
+pub mod create_operator_multisig;
+pub mod set_multisig_signers;
+pub mod propose_action;
+pub mod approve_action;
+pub mod execute_action;
+pub mod close_expired_action;
+pub mod set_timelock_delay;
+pub mod schedule_asset_change;
+pub mod cancel_asset_change;
+pub mod execute_asset_change;
+pub mod set_guardian;
+pub mod set_protocol_pause;
+pub mod set_asset_pause;
+pub mod decommission_asset;
+pub mod deposit_sale_proceeds;
+pub mod redeem_final;
+pub mod close_decommission;
+pub mod create_tender_offer;
+pub mod tender_tokens;
+pub mod withdraw_tender;
+pub mod settle_tender_offer;
+pub mod claim_tender_payment;
+pub mod surrender_tokens;
+pub mod reclaim_tender_funds;
+pub mod cancel_reservation;
+pub mod complete_reservation;
+pub mod expire_charging_session;
+pub mod seed_index;
+pub mod finalize_proceeds;
+pub mod deactivate_subscription_offer;
+pub mod add_index_revenue_vault;
+pub mod collect_index_revenue;
 
 pub use initialize_asset::*;
 pub use create_listing::*;
@@ -11,3 +95,87 @@ pub use buy_asset::*;
 pub use distribute_revenue::*;
 pub use update_compliance::*;
 pub use cancel_listing::*;
+pub use start_distribution::*;
+pub use reconcile_distribution::*;
+pub use finalize_distribution::*;
+pub use fail_distribution::*;
+pub use initialize_protocol_config::*;
+pub use update_payout_mints::*;
+pub use fund_distribution::*;
+pub use claim_revenue::*;
+pub use sweep_unclaimed::*;
+pub use update_revenue_oracles::*;
+pub use set_revenue_oracle::*;
+pub use update_cost_caps::*;
+pub use initialize_revenue_vault::*;
+pub use deposit_revenue::*;
+pub use initialize_spot_calendar::*;
+pub use reserve_spot::*;
+pub use initialize_pricing_schedule::*;
+pub use start_parking_session::*;
+pub use end_parking_session::*;
+pub use set_holder_privilege::*;
+pub use initialize_charging_station::*;
+pub use start_charging_session::*;
+pub use end_charging_session::*;
+pub use register_charger_device::*;
+pub use remove_charger_device::*;
+pub use create_subscription_offer::*;
+pub use purchase_subscription::*;
+pub use renew_subscription::*;
+pub use set_resale_policy::*;
+pub use list_reservation::*;
+pub use buy_reservation::*;
+pub use initialize_bundle::*;
+pub use add_bundle_component::*;
+pub use bundle_tokens::*;
+pub use unbundle_tokens::*;
+pub use collect_bundle_revenue::*;
+pub use initialize_index_fund::*;
+pub use add_index_position::*;
+pub use mint_index::*;
+pub use redeem_index::*;
+pub use rebalance_index::*;
+pub use open_buyback::*;
+pub use redeem_tokens::*;
+pub use close_buyback::*;
+pub use issue_additional_tokens::*;
+pub use exercise_preemption::*;
+pub use subscribe_issuance::*;
+pub use set_compliance_authority::*;
+pub use propose_operator_transfer::*;
+pub use approve_operator_transfer::*;
+pub use accept_operator_transfer::*;
+pub use cancel_operator_transfer::*;
+pub use create_operator_multisig::*;
+pub use set_multisig_signers::*;
+pub use propose_action::*;
+pub use approve_action::*;
+pub use execute_action::*;
+pub use close_expired_action::*;
+pub use set_timelock_delay::*;
+pub use schedule_asset_change::*;
+pub use cancel_asset_change::*;
+pub use execute_asset_change::*;
+pub use set_guardian::*;
+pub use set_protocol_pause::*;
+pub use set_asset_pause::*;
+pub use decommission_asset::*;
+pub use deposit_sale_proceeds::*;
+pub use redeem_final::*;
+pub use close_decommission::*;
+pub use create_tender_offer::*;
+pub use tender_tokens::*;
+pub use withdraw_tender::*;
+pub use settle_tender_offer::*;
+pub use claim_tender_payment::*;
+pub use surrender_tokens::*;
+pub use reclaim_tender_funds::*;
+pub use cancel_reservation::*;
+pub use complete_reservation::*;
+pub use expire_charging_session::*;
+pub use seed_index::*;
+pub use finalize_proceeds::*;
+pub use deactivate_subscription_offer::*;
+pub use add_index_revenue_vault::*;
+pub use collect_index_revenue::*;
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct OpenBuyback<'info> {
+    /// Buyback account PDA
+    #[account(
+        init,
+        payer = operator,
+        space = Buyback::LEN,
+        seeds = [b"buyback", asset_account.key().as_ref()],
+        bump
+    )]
+    pub buyback: Account<'info, Buyback>,
+
+    /// Buyback payment vault
+    #[account(
+        init,
+        payer = operator,
+        seeds = [b"buyback_vault", buyback.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = buyback,
+    )]
+    pub buyback_vault: Account<'info, TokenAccount>,
+
+    /// Payment token mint
+    #[account(
+        constraint = protocol_config.accepts_payout_mint(&payment_mint.key()) @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Operator's payment token account (funds the vault)
+    #[account(
+        mut,
+        constraint = operator_payment_account.owner == operator.key(),
+        constraint = operator_payment_account.mint == payment_mint.key() @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub operator_payment_account: Account<'info, TokenAccount>,
+
+    /// Protocol config (payment mint allowlist)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<OpenBuyback>,
+    price_per_token_usdc: u64,
+    max_tokens: u64,
+    duration_seconds: i64,
+) -> Result<()> {
+    require!(
+        price_per_token_usdc > 0 && duration_seconds > 0,
+        MarketplaceError::InvalidBuyback
+    );
+    require!(
+        max_tokens > 0 && max_tokens <= ctx.accounts.asset_account.circulating_supply,
+        MarketplaceError::InvalidBuyback
+    );
+
+    let clock = Clock::get()?;
+
+    // Fund the vault for the whole buyback up front
+    let funding_usdc = price_per_token_usdc
+        .checked_mul(max_tokens)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.operator_payment_account.to_account_info(),
+            to: ctx.accounts.buyback_vault.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, funding_usdc)?;
+
+    let buyback = &mut ctx.accounts.buyback;
+    buyback.asset_account = ctx.accounts.asset_account.key();
+    buyback.payment_mint = ctx.accounts.payment_mint.key();
+    buyback.price_per_token_usdc = price_per_token_usdc;
+    buyback.max_tokens = max_tokens;
+    buyback.tokens_redeemed = 0;
+    buyback.expires_at = clock
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    buyback.created_at = clock.unix_timestamp;
+    buyback.bump = ctx.bumps.buyback;
+
+    msg!(
+        "Buyback opened: up to {} tokens at {} per token",
+        max_tokens,
+        price_per_token_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
+    /// Pending action PDA
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingAction::LEN,
+        seeds = [
+            b"pending_action",
+            operator_multisig.key().as_ref(),
+            operator_multisig.action_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// Operator multisig
+    #[account(
+        mut,
+        seeds = [b"operator_multisig", operator_multisig.create_key.as_ref()],
+        bump = operator_multisig.bump,
+        constraint = operator_multisig.is_signer(&proposer.key()) @ MarketplaceError::NotMultisigSigner,
+    )]
+    pub operator_multisig: Account<'info, OperatorMultisig>,
+
+    /// Multisig signer proposing the action
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeAction>,
+    accounts: Vec<ActionAccount>,
+    data: Vec<u8>,
+    expires_in_seconds: i64,
+) -> Result<()> {
+    require!(
+        accounts.len() <= PendingAction::MAX_ACCOUNTS
+            && data.len() <= PendingAction::MAX_DATA_LEN
+            && expires_in_seconds > 0,
+        MarketplaceError::InvalidPendingAction
+    );
+
+    let multisig = &mut ctx.accounts.operator_multisig;
+    let action = &mut ctx.accounts.pending_action;
+    let clock = Clock::get()?;
+
+    action.multisig = multisig.key();
+    action.proposer = ctx.accounts.proposer.key();
+    action.index = multisig.action_count;
+    action.accounts = accounts;
+    action.data = data;
+    action.approvals = vec![ctx.accounts.proposer.key()];
+    action.expires_at = clock
+        .unix_timestamp
+        .checked_add(expires_in_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    action.created_at = clock.unix_timestamp;
+    action.bump = ctx.bumps.pending_action;
+
+    multisig.action_count = multisig
+        .action_count
+        .checked_add(1)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Action {} proposed by {}, expires at {}",
+        action.index,
+        action.proposer,
+        action.expires_at
+    );
+
+    Ok(())
+}
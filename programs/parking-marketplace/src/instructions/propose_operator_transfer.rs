@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+#[instruction(parking_lot_id: u64)]
+pub struct ProposeOperatorTransfer<'info> {
+    /// Operator transfer PDA
+    #[account(
+        init,
+        payer = current_operator,
+        space = OperatorTransfer::LEN,
+        seeds = [
+            b"operator_transfer",
+            parking_lot_id.to_le_bytes().as_ref(),
+            current_operator.key().as_ref()
+        ],
+        bump
+    )]
+    pub operator_transfer: Account<'info, OperatorTransfer>,
+
+    /// Protocol config (compliance authority)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Current operator of the lot's assets
+    #[account(mut)]
+    pub current_operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeOperatorTransfer>,
+    parking_lot_id: u64,
+    proposed_operator: Pubkey,
+    asset_count: u32,
+) -> Result<()> {
+    require!(
+        proposed_operator != Pubkey::default()
+            && proposed_operator != ctx.accounts.current_operator.key()
+            && asset_count > 0,
+        MarketplaceError::InvalidOperatorTransfer
+    );
+
+    let transfer = &mut ctx.accounts.operator_transfer;
+    let clock = Clock::get()?;
+
+    transfer.parking_lot_id = parking_lot_id;
+    transfer.current_operator = ctx.accounts.current_operator.key();
+    transfer.proposed_operator = proposed_operator;
+    transfer.asset_count = asset_count;
+    transfer.assets_transferred = 0;
+    transfer.requires_approval = ctx.accounts.protocol_config.requires_transfer_approval();
+    transfer.is_approved = false;
+    transfer.proposed_at = clock.unix_timestamp;
+    transfer.bump = ctx.bumps.operator_transfer;
+
+    msg!(
+        "Operator transfer proposed for lot {}: {} assets, {} -> {}",
+        parking_lot_id,
+        asset_count,
+        transfer.current_operator,
+        proposed_operator
+    );
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount, Transfer};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct PurchaseSubscription<'info> {
+    /// Subscription pass PDA
+    #[account(
+        init,
+        payer = buyer,
+        space = SubscriptionPass::LEN,
+        seeds = [b"subscription_pass", pass_mint.key().as_ref()],
+        bump
+    )]
+    pub pass_account: Account<'info, SubscriptionPass>,
+
+    /// Non-fungible token mint for the pass
+    #[account(
+        init,
+        payer = buyer,
+        mint::decimals = 0,
+        mint::authority = pass_account,
+    )]
+    pub pass_mint: Account<'info, Mint>,
+
+    /// Token account receiving the pass
+    #[account(
+        init,
+        payer = buyer,
+        token::mint = pass_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_pass_token_account: Account<'info, TokenAccount>,
+
+    /// Subscription offer
+    #[account(
+        constraint = offer_account.asset_account == asset_account.key() @ MarketplaceError::InvalidSubscription,
+        constraint = offer_account.is_active @ MarketplaceError::InvalidSubscription,
+    )]
+    pub offer_account: Account<'info, SubscriptionOffer>,
+
+    /// Parking asset receiving subscription revenue
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault (receives the payment)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            offer_account.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Buyer's payment token account
+    #[account(
+        mut,
+        constraint = buyer_payment_account.owner == buyer.key(),
+        constraint = buyer_payment_account.mint == offer_account.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub buyer_payment_account: Account<'info, TokenAccount>,
+
+    /// Buyer
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<PurchaseSubscription>,
+    valid_from: i64,
+) -> Result<()> {
+    let offer = &ctx.accounts.offer_account;
+    let clock = Clock::get()?;
+
+    require!(
+        valid_from >= clock.unix_timestamp,
+        MarketplaceError::InvalidSubscriptionPeriod
+    );
+    let valid_until = valid_from
+        .checked_add(offer.duration_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Pay into the asset's revenue vault
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.buyer_payment_account.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, offer.price_usdc)?;
+
+    ctx.accounts
+        .revenue_vault
+        .record_inflow(offer.price_usdc, clock.unix_timestamp)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Initialize pass
+    let pass = &mut ctx.accounts.pass_account;
+    pass.pass_mint = ctx.accounts.pass_mint.key();
+    pass.offer = offer.key();
+    pass.parking_lot_id = offer.parking_lot_id;
+    pass.tier = offer.tier;
+    pass.valid_from = valid_from;
+    pass.valid_until = valid_until;
+    pass.created_at = clock.unix_timestamp;
+    pass.bump = ctx.bumps.pass_account;
+
+    // Mint exactly one pass token, then drop the mint authority
+    let pass_mint_key = pass.pass_mint;
+    let seeds = &[
+        b"subscription_pass",
+        pass_mint_key.as_ref(),
+        &[pass.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.pass_mint.to_account_info(),
+            to: ctx.accounts.buyer_pass_token_account.to_account_info(),
+            authority: pass.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, 1)?;
+
+    let authority_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        SetAuthority {
+            account_or_mint: ctx.accounts.pass_mint.to_account_info(),
+            current_authority: pass.to_account_info(),
+        },
+        signer,
+    );
+    token::set_authority(authority_ctx, AuthorityType::MintTokens, None)?;
+
+    msg!(
+        "Subscription pass {} issued for lot {} tier {} until {}",
+        pass_mint_key,
+        pass.parking_lot_id,
+        pass.tier,
+        valid_until
+    );
+
+    Ok(())
+}
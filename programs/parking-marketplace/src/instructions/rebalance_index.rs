@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RebalanceIndex<'info> {
+    /// Index fund (vault authority)
+    #[account(
+        mut,
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+        constraint = index_fund.manager == manager.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Manager's token account for the underweight position (source)
+    #[account(
+        mut,
+        constraint = manager_buy_account.owner == manager.key(),
+    )]
+    pub manager_buy_account: Account<'info, TokenAccount>,
+
+    /// Manager's token account for the overweight position (destination)
+    #[account(
+        mut,
+        constraint = manager_sell_account.owner == manager.key(),
+    )]
+    pub manager_sell_account: Account<'info, TokenAccount>,
+
+    /// Index manager
+    pub manager: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: for each position in order,
+    // [asset account, position vault (mut for the two traded positions)]
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RebalanceIndex<'info>>,
+    sell_index: u8,
+    buy_index: u8,
+    amount_in: u64,
+) -> Result<()> {
+    require!(amount_in > 0, MarketplaceError::InvalidTokenAmount);
+    require!(sell_index != buy_index, MarketplaceError::InvalidIndexAccounts);
+
+    let index_fund = &ctx.accounts.index_fund;
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp
+            >= index_fund
+                .last_rebalanced_at
+                .saturating_add(index_fund.min_rebalance_interval),
+        MarketplaceError::RebalanceTooSoon
+    );
+
+    let sell = *index_fund
+        .positions
+        .get(sell_index as usize)
+        .ok_or(MarketplaceError::InvalidIndexAccounts)?;
+    let buy = *index_fund
+        .positions
+        .get(buy_index as usize)
+        .ok_or(MarketplaceError::InvalidIndexAccounts)?;
+    require!(
+        ctx.accounts.manager_buy_account.mint == buy.asset_token_mint
+            && ctx.accounts.manager_sell_account.mint == sell.asset_token_mint,
+        MarketplaceError::InvalidIndexAccounts
+    );
+
+    let values = index_fund.position_values(ctx.remaining_accounts)?;
+    let nav = values
+        .iter()
+        .try_fold(0u64, |total, value| total.checked_add(*value))
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let sell_value = values[sell_index as usize];
+    let buy_value = values[buy_index as usize];
+
+    // Only trade an overweight position for an underweight one, and only
+    // once at least one of them has drifted outside its band
+    require!(
+        weight_bps(sell_value, nav) > sell.target_weight_bps as u64
+            && weight_bps(buy_value, nav) < buy.target_weight_bps as u64
+            && (!index_fund.is_within_band(&sell, sell_value, nav)
+                || !index_fund.is_within_band(&buy, buy_value, nav)),
+        MarketplaceError::RebalanceOutsideBands
+    );
+
+    // Swap at each asset's estimated value
+    let sell_asset_info = &ctx.remaining_accounts[sell_index as usize * 2];
+    let sell_vault_info = &ctx.remaining_accounts[sell_index as usize * 2 + 1];
+    let buy_asset_info = &ctx.remaining_accounts[buy_index as usize * 2];
+    let buy_vault_info = &ctx.remaining_accounts[buy_index as usize * 2 + 1];
+    let sell_asset = Account::<ParkingAsset>::try_from(sell_asset_info)?;
+    let buy_asset = Account::<ParkingAsset>::try_from(buy_asset_info)?;
+
+    let value_in = buy_asset
+        .token_value(amount_in)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let amount_out = sell_asset
+        .tokens_for_value(value_in)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let value_out = sell_asset
+        .token_value(amount_out)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(amount_out > 0, MarketplaceError::InvalidTokenAmount);
+
+    // Neither side may overshoot its band
+    let new_nav = nav
+        .checked_add(value_in)
+        .and_then(|total| total.checked_sub(value_out))
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    let new_sell_value = sell_value
+        .checked_sub(value_out)
+        .ok_or(MarketplaceError::InsufficientBalance)?;
+    let new_buy_value = buy_value
+        .checked_add(value_in)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    require!(
+        weight_bps(new_sell_value, new_nav) + index_fund.band_bps as u64
+            >= sell.target_weight_bps as u64
+            && weight_bps(new_buy_value, new_nav)
+                <= buy.target_weight_bps as u64 + index_fund.band_bps as u64,
+        MarketplaceError::RebalanceOutsideBands
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.manager_buy_account.to_account_info(),
+            to: buy_vault_info.clone(),
+            authority: ctx.accounts.manager.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount_in)?;
+
+    let index_mint_key = index_fund.index_mint;
+    let seeds = &[
+        b"index_fund",
+        index_mint_key.as_ref(),
+        &[index_fund.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: sell_vault_info.clone(),
+            to: ctx.accounts.manager_sell_account.to_account_info(),
+            authority: index_fund.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount_out)?;
+
+    let index_fund = &mut ctx.accounts.index_fund;
+    index_fund.last_rebalanced_at = clock.unix_timestamp;
+
+    msg!(
+        "Index rebalanced: {} of position {} for {} of position {}",
+        amount_in,
+        buy_index,
+        amount_out,
+        sell_index
+    );
+
+    Ok(())
+}
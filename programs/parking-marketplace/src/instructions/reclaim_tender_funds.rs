@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ReclaimTenderFunds<'info> {
+    /// Failed tender offer (closed once every tender is withdrawn)
+    #[account(
+        mut,
+        seeds = [
+            b"tender_offer",
+            tender_offer.asset_account.as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+        constraint = tender_offer.bidder == bidder.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = tender_offer.status == TenderOfferStatus::Failed @ MarketplaceError::TenderOfferNotFailed,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Payment escrow
+    #[account(
+        mut,
+        seeds = [b"tender_escrow", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub payment_escrow: Account<'info, TokenAccount>,
+
+    /// Escrow holding tendered asset tokens
+    #[account(
+        mut,
+        seeds = [b"tender_tokens", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub token_escrow: Account<'info, TokenAccount>,
+
+    /// Bidder's payment token account (receives the refund)
+    #[account(
+        mut,
+        constraint = bidder_payment_account.owner == bidder.key(),
+        constraint = bidder_payment_account.mint == tender_offer.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub bidder_payment_account: Account<'info, TokenAccount>,
+
+    /// Bidder
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<ReclaimTenderFunds>,
+) -> Result<()> {
+    let offer = &ctx.accounts.tender_offer;
+    let refund_usdc = ctx.accounts.payment_escrow.amount;
+
+    let asset_key = offer.asset_account;
+    let bidder_key = offer.bidder;
+    let seeds = &[
+        b"tender_offer",
+        asset_key.as_ref(),
+        bidder_key.as_ref(),
+        &[offer.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    if refund_usdc > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payment_escrow.to_account_info(),
+                to: ctx.accounts.bidder_payment_account.to_account_info(),
+                authority: offer.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund_usdc)?;
+    }
+
+    // Once every tender is withdrawn, close the offer so a new one can be made
+    if offer.tokens_tendered == 0 {
+        for escrow in [
+            ctx.accounts.payment_escrow.to_account_info(),
+            ctx.accounts.token_escrow.to_account_info(),
+        ] {
+            let close_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: escrow,
+                    destination: ctx.accounts.bidder.to_account_info(),
+                    authority: offer.to_account_info(),
+                },
+                signer,
+            );
+            token::close_account(close_ctx)?;
+        }
+
+        ctx.accounts
+            .tender_offer
+            .close(ctx.accounts.bidder.to_account_info())?;
+    }
+
+    msg!("Tender offer funds reclaimed: {}", refund_usdc);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ReconcileDistribution<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Distribution vault being reconciled
+    #[account(
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator reconciling the distribution
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ReconcileDistribution>,
+) -> Result<()> {
+    let distribution = &mut ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.distribution_status != DistributionStatus::Failed,
+        MarketplaceError::InvalidDistributionStatus
+    );
+
+    // Funding less recorded claims must still be in the vault
+    let expected_usdc = distribution
+        .expected_vault_balance()
+        .ok_or(MarketplaceError::DistributionOverpaid)?;
+    let vault_balance = ctx.accounts.distribution_vault.amount;
+    require!(
+        vault_balance >= expected_usdc,
+        MarketplaceError::DistributionVaultShortfall
+    );
+
+    // Tokens sent straight to the vault are not holder money; track them
+    // as operator funds so they can be swept back
+    let surplus_usdc = vault_balance - expected_usdc;
+    distribution.operator_funded_usdc = distribution
+        .operator_funded_usdc
+        .checked_add(surplus_usdc)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    distribution.reconciled_at = clock.unix_timestamp;
+
+    msg!(
+        "Distribution reconciled: {} paid, {} held, {} unrecorded surplus",
+        distribution.total_distributed_usdc,
+        vault_balance,
+        surplus_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RedeemFinal<'info> {
+    /// Decommission account
+    #[account(
+        mut,
+        seeds = [b"decommission", asset_account.key().as_ref()],
+        bump = decommission.bump,
+    )]
+    pub decommission: Account<'info, Decommission>,
+
+    /// Vault holding the sale proceeds
+    #[account(
+        mut,
+        seeds = [b"decommission_vault", decommission.key().as_ref()],
+        bump,
+    )]
+    pub decommission_vault: Account<'info, TokenAccount>,
+
+    /// Parking asset being wound down
+    #[account(
+        mut,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Asset token mint
+    #[account(
+        mut,
+        address = asset_account.asset_token_mint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Holder's asset token account (tokens are burned from it)
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == token_mint.key(),
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's payment token account (receives the pro-rata proceeds)
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == decommission.payment_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub holder_payment_account: Account<'info, TokenAccount>,
+
+    /// Token holder
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<RedeemFinal>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let decommission = &mut ctx.accounts.decommission;
+    let asset = &mut ctx.accounts.asset_account;
+    let clock = Clock::get()?;
+
+    require!(
+        decommission.proceeds_finalized,
+        MarketplaceError::ProceedsNotFinalized
+    );
+    require!(
+        decommission.is_redemption_open(clock.unix_timestamp),
+        MarketplaceError::RedemptionWindowClosed
+    );
+    require!(
+        amount <= asset.circulating_supply,
+        MarketplaceError::InsufficientBalance
+    );
+
+    // Share of what is left for the tokens still outstanding
+    let payout_usdc = Decommission::redemption_amount(
+        ctx.accounts.decommission_vault.amount,
+        amount,
+        asset.circulating_supply,
+    );
+    require!(payout_usdc > 0, MarketplaceError::NothingToClaim);
+
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.token_mint.to_account_info(),
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::burn(burn_ctx, amount)?;
+
+    let asset_key = decommission.asset_account;
+    let seeds = &[
+        b"decommission",
+        asset_key.as_ref(),
+        &[decommission.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.decommission_vault.to_account_info(),
+            to: ctx.accounts.holder_payment_account.to_account_info(),
+            authority: decommission.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, payout_usdc)?;
+
+    decommission.tokens_redeemed = decommission
+        .tokens_redeemed
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    decommission.amount_paid_usdc = decommission
+        .amount_paid_usdc
+        .checked_add(payout_usdc)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    asset.circulating_supply -= amount;
+    asset.total_supply = asset.total_supply.saturating_sub(amount);
+
+    msg!(
+        "Final redemption: {} tokens burned for {}",
+        amount,
+        payout_usdc
+    );
+
+    Ok(())
+}
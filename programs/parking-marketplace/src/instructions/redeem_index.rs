@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RedeemIndex<'info> {
+    /// Index fund (vault authority)
+    #[account(
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Index token mint
+    #[account(
+        mut,
+        address = index_fund.index_mint,
+    )]
+    pub index_mint: Account<'info, Mint>,
+
+    /// Holder's index token account (index tokens are burned from it)
+    #[account(
+        mut,
+        constraint = holder_index_account.owner == holder.key(),
+        constraint = holder_index_account.mint == index_mint.key(),
+    )]
+    pub holder_index_account: Account<'info, TokenAccount>,
+
+    /// Index token holder
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: for each basket vault in order (positions, then
+    // revenue vaults), [vault (mut), holder token account for its mint (mut)]
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemIndex<'info>>,
+    index_amount: u64,
+) -> Result<()> {
+    require!(index_amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let index_fund = &ctx.accounts.index_fund;
+    let supply = ctx.accounts.index_mint.supply;
+    require!(
+        ctx.remaining_accounts.len() == index_fund.basket_len() * 2,
+        MarketplaceError::InvalidIndexAccounts
+    );
+
+    let index_mint_key = index_fund.index_mint;
+    let seeds = &[
+        b"index_fund",
+        index_mint_key.as_ref(),
+        &[index_fund.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Pay out a pro-rata slice of every vault, collected revenue included
+    for (vault_key, pair) in index_fund.basket_vaults().zip(ctx.remaining_accounts.chunks(2)) {
+        require!(
+            pair[0].key() == vault_key,
+            MarketplaceError::InvalidIndexAccounts
+        );
+        let vault = Account::<TokenAccount>::try_from(&pair[0])?;
+        let amount = IndexFund::basket_withdrawal(vault.amount, index_amount, supply)
+            .ok_or(MarketplaceError::InsufficientBalance)?;
+        if amount == 0 {
+            continue;
+        }
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: pair[0].clone(),
+                to: pair[1].clone(),
+                authority: index_fund.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, amount)?;
+    }
+
+    // Burn the index tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.index_mint.to_account_info(),
+            from: ctx.accounts.holder_index_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::burn(burn_ctx, index_amount)?;
+
+    msg!(
+        "Index redeemed: {} index tokens for a basket of {} positions",
+        index_amount,
+        index_fund.positions.len()
+    );
+
+    Ok(())
+}
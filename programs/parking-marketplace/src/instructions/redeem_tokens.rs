@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RedeemTokens<'info> {
+    /// Buyback account
+    #[account(
+        mut,
+        seeds = [b"buyback", asset_account.key().as_ref()],
+        bump = buyback.bump,
+    )]
+    pub buyback: Account<'info, Buyback>,
+
+    /// Buyback payment vault
+    #[account(
+        mut,
+        seeds = [b"buyback_vault", buyback.key().as_ref()],
+        bump,
+    )]
+    pub buyback_vault: Account<'info, TokenAccount>,
+
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Asset token mint
+    #[account(
+        mut,
+        address = asset_account.asset_token_mint,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// Holder's asset token account (tokens are burned from it)
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == token_mint.key(),
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's payment token account (receives the buyback price)
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == buyback.payment_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub holder_payment_account: Account<'info, TokenAccount>,
+
+    /// Token holder selling into the buyback
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<RedeemTokens>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let buyback = &mut ctx.accounts.buyback;
+    let clock = Clock::get()?;
+
+    require!(
+        buyback.is_open(clock.unix_timestamp),
+        MarketplaceError::BuybackClosed
+    );
+    require!(
+        amount <= buyback.remaining_tokens(),
+        MarketplaceError::BuybackClosed
+    );
+
+    let payment_usdc = buyback
+        .price_per_token_usdc
+        .checked_mul(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Burn the holder's tokens
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.token_mint.to_account_info(),
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::burn(burn_ctx, amount)?;
+
+    // Pay the published price from the buyback vault
+    let asset_key = buyback.asset_account;
+    let seeds = &[
+        b"buyback",
+        asset_key.as_ref(),
+        &[buyback.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.buyback_vault.to_account_info(),
+            to: ctx.accounts.holder_payment_account.to_account_info(),
+            authority: buyback.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, payment_usdc)?;
+
+    buyback.tokens_redeemed = buyback
+        .tokens_redeemed
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Fewer tokens outstanding raises revenue per token for remaining holders
+    let asset = &mut ctx.accounts.asset_account;
+    asset.circulating_supply = asset
+        .circulating_supply
+        .checked_sub(amount)
+        .ok_or(MarketplaceError::InsufficientBalance)?;
+    asset.total_supply = asset
+        .total_supply
+        .checked_sub(amount)
+        .ok_or(MarketplaceError::InsufficientBalance)?;
+
+    msg!(
+        "Tokens redeemed: {} burned for {}",
+        amount,
+        payment_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RegisterChargerDevice<'info> {
+    /// Charging station
+    #[account(
+        mut,
+        seeds = [b"charging_station", asset_account.key().as_ref()],
+        bump = station_account.bump,
+    )]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Parking asset owning the station
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterChargerDevice>,
+    device_key: Pubkey,
+    connector_id: u8,
+) -> Result<()> {
+    let station = &mut ctx.accounts.station_account;
+
+    require!(
+        connector_id < station.total_connectors,
+        MarketplaceError::InvalidConnector
+    );
+    require!(
+        station.find_device_mut(&device_key).is_none(),
+        MarketplaceError::ChargerDeviceAlreadyRegistered
+    );
+    require!(
+        station.charger_devices.len() < ChargingStation::MAX_CHARGER_DEVICES,
+        MarketplaceError::TooManyChargerDevices
+    );
+
+    station.charger_devices.push(ChargerDevice {
+        device_key,
+        connector_id,
+        last_reading_at: 0,
+    });
+
+    msg!(
+        "Charger device {} registered for station {} connector {}",
+        device_key,
+        station.station_id,
+        connector_id
+    );
+
+    Ok(())
+}
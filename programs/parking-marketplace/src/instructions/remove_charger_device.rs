@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RemoveChargerDevice<'info> {
+    /// Charging station
+    #[account(
+        mut,
+        seeds = [b"charging_station", asset_account.key().as_ref()],
+        bump = station_account.bump,
+    )]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Parking asset owning the station
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RemoveChargerDevice>,
+    device_key: Pubkey,
+) -> Result<()> {
+    let station = &mut ctx.accounts.station_account;
+
+    let devices_before = station.charger_devices.len();
+    station
+        .charger_devices
+        .retain(|device| device.device_key != device_key);
+    require!(
+        station.charger_devices.len() < devices_before,
+        MarketplaceError::ChargerDeviceNotRegistered
+    );
+
+    msg!(
+        "Charger device {} removed from station {}",
+        device_key,
+        station.station_id
+    );
+
+    Ok(())
+}
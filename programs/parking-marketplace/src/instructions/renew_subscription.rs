@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct RenewSubscription<'info> {
+    /// Subscription pass
+    #[account(
+        mut,
+        seeds = [b"subscription_pass", pass_account.pass_mint.as_ref()],
+        bump = pass_account.bump,
+        constraint = pass_account.offer == offer_account.key() @ MarketplaceError::InvalidSubscription,
+    )]
+    pub pass_account: Account<'info, SubscriptionPass>,
+
+    /// Holder's pass token account (proves ownership of the pass)
+    #[account(
+        constraint = holder_pass_token_account.owner == holder.key(),
+        constraint = holder_pass_token_account.mint == pass_account.pass_mint @ MarketplaceError::InvalidSubscription,
+        constraint = holder_pass_token_account.amount == 1 @ MarketplaceError::InvalidSubscription,
+    )]
+    pub holder_pass_token_account: Account<'info, TokenAccount>,
+
+    /// Subscription offer
+    #[account(
+        constraint = offer_account.asset_account == asset_account.key() @ MarketplaceError::InvalidSubscription,
+        constraint = offer_account.is_active @ MarketplaceError::InvalidSubscription,
+    )]
+    pub offer_account: Account<'info, SubscriptionOffer>,
+
+    /// Parking asset receiving subscription revenue
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault (receives the payment)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            offer_account.payment_mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's payment token account
+    #[account(
+        mut,
+        constraint = holder_payment_account.owner == holder.key(),
+        constraint = holder_payment_account.mint == offer_account.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub holder_payment_account: Account<'info, TokenAccount>,
+
+    /// Pass holder
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<RenewSubscription>,
+) -> Result<()> {
+    let offer = &ctx.accounts.offer_account;
+    let clock = Clock::get()?;
+
+    // Pay into the asset's revenue vault
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.holder_payment_account.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, offer.price_usdc)?;
+
+    ctx.accounts
+        .revenue_vault
+        .record_inflow(offer.price_usdc, clock.unix_timestamp)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Extend from the current expiry, or from now if the pass has lapsed
+    let pass = &mut ctx.accounts.pass_account;
+    let renew_from = pass.valid_until.max(clock.unix_timestamp);
+    if pass.valid_until < clock.unix_timestamp {
+        pass.valid_from = clock.unix_timestamp;
+    }
+    pass.valid_until = renew_from
+        .checked_add(offer.duration_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Subscription pass {} renewed until {}",
+        pass.pass_mint,
+        pass.valid_until
+    );
+
+    Ok(())
+}
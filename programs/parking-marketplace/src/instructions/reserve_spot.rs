@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+use crate::pricing;
+
+#[derive(Accounts)]
+#[instruction(start_time: i64)]
+pub struct ReserveSpot<'info> {
+    /// Reservation account PDA
+    #[account(
+        init,
+        payer = driver,
+        space = Reservation::LEN,
+        seeds = [
+            b"reservation",
+            asset_account.key().as_ref(),
+            start_time.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// Spot calendar
+    #[account(
+        mut,
+        seeds = [b"spot_calendar", asset_account.key().as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Pricing schedule for the spot
+    #[account(
+        seeds = [b"pricing_schedule", asset_account.key().as_ref()],
+        bump = pricing_schedule.bump,
+    )]
+    pub pricing_schedule: Account<'info, PricingSchedule>,
+
+    /// Parking asset being reserved
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault (receives the payment)
+    #[account(
+        mut,
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            revenue_vault.mint.as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding collected revenue
+    #[account(
+        mut,
+        address = revenue_vault.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Driver's payment token account
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == driver.key(),
+        constraint = driver_payment_account.mint == revenue_vault.mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// Driver's asset token account (optional, for holder privileges)
+    #[account(
+        constraint = holder_token_account.owner == driver.key(),
+        constraint = holder_token_account.mint == asset_account.asset_token_mint,
+    )]
+    pub holder_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Driver's subscription pass (optional, covers the reservation if active)
+    pub subscription_pass: Option<Account<'info, SubscriptionPass>>,
+
+    /// Offer the pass was issued from (required with the pass)
+    pub subscription_offer: Option<Account<'info, SubscriptionOffer>>,
+
+    /// Asset the offer was created for (required when the pass is used on
+    /// another asset of the same lot)
+    pub subscription_offer_asset: Option<Account<'info, ParkingAsset>>,
+
+    /// Driver's pass token account (optional, proves ownership of the pass)
+    pub pass_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Driver making the reservation
+    #[account(mut)]
+    pub driver: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReserveSpot>,
+    start_time: i64,
+    end_time: i64,
+    max_price_usdc: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate period
+    require!(
+        start_time >= clock.unix_timestamp && end_time > start_time,
+        MarketplaceError::InvalidReservationPeriod
+    );
+    require!(
+        end_time - start_time <= pricing::MAX_QUOTE_SECONDS,
+        MarketplaceError::InvalidReservationPeriod
+    );
+
+    // Check the spot is free and book it
+    let calendar = &mut ctx.accounts.spot_calendar;
+    calendar.prune(clock.unix_timestamp);
+    require!(
+        calendar.is_available(start_time, end_time),
+        MarketplaceError::SpotUnavailable
+    );
+    require!(
+        calendar.bookings_held_by(&ctx.accounts.driver.key()) < SpotCalendar::MAX_BOOKINGS_PER_DRIVER,
+        MarketplaceError::DriverBookingLimitReached
+    );
+
+    let list_price_usdc =
+        pricing::quote(&ctx.accounts.pricing_schedule.rates, start_time, end_time)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    // Active subscription passes park for free; otherwise holders get their discount
+    let driver_key = ctx.accounts.driver.key();
+    let asset = &ctx.accounts.asset_account;
+    let covered_by_pass = match (
+        &ctx.accounts.subscription_pass,
+        &ctx.accounts.subscription_offer,
+        &ctx.accounts.pass_token_account,
+    ) {
+        (Some(pass), Some(offer), Some(pass_token_account)) => {
+            pass.is_held_by(pass_token_account, &driver_key)
+                && pass.covers(&offer.key(), start_time, end_time)
+                && offer.applies_to(
+                    &asset.key(),
+                    asset,
+                    ctx.accounts.subscription_offer_asset.as_ref(),
+                )
+        }
+        _ => false,
+    };
+    // Passes are transferable, so cap free bookings per pass as well as per
+    // wallet
+    let covering_pass = match &ctx.accounts.subscription_pass {
+        Some(pass) if covered_by_pass => pass.key(),
+        _ => Pubkey::default(),
+    };
+    if covered_by_pass {
+        require!(
+            calendar.bookings_covered_by(&covering_pass) < SpotCalendar::MAX_BOOKINGS_PER_PASS,
+            MarketplaceError::PassBookingLimitReached
+        );
+    }
+    let holder_balance = ctx
+        .accounts
+        .holder_token_account
+        .as_ref()
+        .map_or(0, |account| account.amount);
+    let price_usdc = if covered_by_pass {
+        0
+    } else {
+        ctx.accounts
+            .asset_account
+            .holder_price(list_price_usdc, holder_balance)
+    };
+    require!(
+        price_usdc <= max_price_usdc,
+        MarketplaceError::PriceExceedsMaximum
+    );
+
+    require!(
+        calendar.book(start_time, end_time, driver_key, covering_pass),
+        MarketplaceError::CalendarFull
+    );
+
+    // Pay into the asset's revenue vault
+    if price_usdc > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.driver_payment_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.driver.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, price_usdc)?;
+
+        ctx.accounts
+            .revenue_vault
+            .record_inflow(price_usdc, clock.unix_timestamp)
+            .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    }
+
+    // Initialize reservation
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.asset_account = ctx.accounts.asset_account.key();
+    reservation.driver = ctx.accounts.driver.key();
+    reservation.start_time = start_time;
+    reservation.end_time = end_time;
+    reservation.price_paid_usdc = price_usdc;
+    reservation.original_price_usdc = price_usdc;
+    reservation.payment_mint = ctx.accounts.revenue_vault.mint;
+    reservation.status = ReservationStatus::Active;
+    reservation.created_at = clock.unix_timestamp;
+    reservation.bump = ctx.bumps.reservation;
+
+    msg!(
+        "Spot reserved: asset {} from {} to {} for {}",
+        reservation.asset_account,
+        start_time,
+        end_time,
+        price_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct ScheduleAssetChange<'info> {
+    /// Timelocked change PDA
+    #[account(
+        init,
+        payer = operator,
+        space = TimelockedChange::LEN,
+        seeds = [
+            b"timelocked_change",
+            asset_account.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub timelocked_change: Account<'info, TimelockedChange>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (timelock delay)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator (must be asset owner)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleAssetChange>,
+    change: AssetChange,
+) -> Result<()> {
+    let asset = &ctx.accounts.asset_account;
+    let clock = Clock::get()?;
+
+    require!(
+        change.is_valid_for(asset, &ctx.accounts.protocol_config),
+        MarketplaceError::InvalidTimelockedChange
+    );
+
+    let timelocked = &mut ctx.accounts.timelocked_change;
+    timelocked.asset_account = asset.key();
+    timelocked.operator = ctx.accounts.operator.key();
+    timelocked.change = change;
+    timelocked.scheduled_at = clock.unix_timestamp;
+    timelocked.executable_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.protocol_config.timelock_delay())
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    timelocked.bump = ctx.bumps.timelocked_change;
+
+    msg!(
+        "Asset change scheduled for {}, executable at {}",
+        asset.key(),
+        timelocked.executable_at
+    );
+
+    Ok(())
+}
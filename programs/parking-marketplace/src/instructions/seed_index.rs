@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SeedIndex<'info> {
+    /// Index fund (mint authority for index tokens)
+    #[account(
+        seeds = [b"index_fund", index_fund.index_mint.as_ref()],
+        bump = index_fund.bump,
+        constraint = index_fund.is_fully_weighted() @ MarketplaceError::InvalidIndexWeights,
+    )]
+    pub index_fund: Account<'info, IndexFund>,
+
+    /// Index token mint
+    #[account(
+        mut,
+        address = index_fund.index_mint,
+    )]
+    pub index_mint: Account<'info, Mint>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.issuance @ MarketplaceError::IssuancePaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Index token account holding the dead shares, owned by the index fund
+    /// and never spent
+    #[account(
+        init,
+        payer = depositor,
+        token::mint = index_mint,
+        token::authority = index_fund,
+        seeds = [b"index_dead_shares", index_fund.key().as_ref()],
+        bump
+    )]
+    pub dead_shares_account: Account<'info, TokenAccount>,
+
+    /// Depositor's index token account
+    #[account(
+        mut,
+        constraint = depositor_index_account.mint == index_mint.key(),
+    )]
+    pub depositor_index_account: Account<'info, TokenAccount>,
+
+    /// Depositor
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+    // remaining_accounts: for each position in order,
+    // [asset account, position vault (mut)], followed by the depositor's
+    // asset token account (mut) for each position in order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SeedIndex<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let index_fund = &ctx.accounts.index_fund;
+    let position_count = index_fund.positions.len();
+    require!(
+        ctx.accounts.index_mint.supply == 0,
+        MarketplaceError::IndexAlreadySeeded
+    );
+    require!(
+        amounts.len() == position_count,
+        MarketplaceError::InvalidIndexAccounts
+    );
+    require!(
+        ctx.remaining_accounts.len() == position_count * 3,
+        MarketplaceError::InvalidIndexAccounts
+    );
+
+    let (position_accounts, depositor_accounts) =
+        ctx.remaining_accounts.split_at(position_count * 2);
+
+    for (((position, amount), pair), depositor_info) in index_fund
+        .positions
+        .iter()
+        .zip(amounts.iter())
+        .zip(position_accounts.chunks(2))
+        .zip(depositor_accounts.iter())
+    {
+        require!(*amount > 0, MarketplaceError::InvalidTokenAmount);
+        require!(
+            pair[1].key() == position.vault,
+            MarketplaceError::InvalidIndexAccounts
+        );
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: depositor_info.clone(),
+                to: pair[1].clone(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, *amount)?;
+    }
+
+    // The first deposit sets the composition every later basket follows,
+    // so it must already sit within the weight bands
+    let values = index_fund.position_values(position_accounts)?;
+    let nav = values
+        .iter()
+        .try_fold(0u64, |total, value| total.checked_add(*value))
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+    for (position, value) in index_fund.positions.iter().zip(values.iter()) {
+        require!(
+            index_fund.is_within_band(position, *value, nav),
+            MarketplaceError::InvalidIndexWeights
+        );
+    }
+
+    let (depositor_amount, dead_amount) =
+        IndexFund::seed_allocation(nav).ok_or(MarketplaceError::IndexSeedTooSmall)?;
+
+    let index_mint_key = index_fund.index_mint;
+    let seeds = &[
+        b"index_fund",
+        index_mint_key.as_ref(),
+        &[index_fund.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.index_mint.to_account_info(),
+            to: ctx.accounts.depositor_index_account.to_account_info(),
+            authority: index_fund.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, depositor_amount)?;
+
+    let dead_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.index_mint.to_account_info(),
+            to: ctx.accounts.dead_shares_account.to_account_info(),
+            authority: index_fund.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(dead_ctx, dead_amount)?;
+
+    msg!(
+        "Index seeded: NAV {} USDC, {} index tokens minted, {} locked",
+        nav,
+        depositor_amount,
+        dead_amount
+    );
+
+    Ok(())
+}
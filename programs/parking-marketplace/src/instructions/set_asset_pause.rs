@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetAssetPause<'info> {
+    /// Parking asset
+    #[account(mut)]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (guardian)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.guardian == guardian.key() @ MarketplaceError::UnauthorizedGuardian,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Emergency guardian
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetAssetPause>,
+    paused: PauseFlags,
+) -> Result<()> {
+    let asset = &mut ctx.accounts.asset_account;
+
+    asset.paused = paused;
+
+    msg!(
+        "Pause flags for asset {} set: trading {}, distributions {}, issuance {}",
+        asset.key(),
+        paused.trading,
+        paused.distributions,
+        paused.issuance
+    );
+
+    Ok(())
+}
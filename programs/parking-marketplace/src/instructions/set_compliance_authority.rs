@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MarketplaceError::UnauthorizedAdmin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetComplianceAuthority>,
+    compliance_authority: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.compliance_authority = compliance_authority.unwrap_or_default();
+
+    msg!(
+        "Compliance authority set to {}",
+        config.compliance_authority
+    );
+
+    Ok(())
+}
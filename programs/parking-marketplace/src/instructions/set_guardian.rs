@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MarketplaceError::UnauthorizedAdmin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetGuardian>,
+    guardian: Pubkey,
+) -> Result<()> {
+    // Pausing must not depend on the admin key
+    require!(
+        guardian != ctx.accounts.admin.key(),
+        MarketplaceError::UnauthorizedGuardian
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.guardian = guardian;
+
+    msg!("Guardian set to {}", guardian);
+
+    Ok(())
+}
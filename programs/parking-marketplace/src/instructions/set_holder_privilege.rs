@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetHolderPrivilege<'info> {
+    /// Parking asset (must be a single spot)
+    #[account(
+        mut,
+        constraint = asset_account.asset_type == AssetType::SingleSpot @ MarketplaceError::InvalidAssetType,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetHolderPrivilege>,
+    holder_privilege: HolderPrivilege,
+) -> Result<()> {
+    require!(
+        holder_privilege.is_valid(),
+        MarketplaceError::InvalidHolderPrivilege
+    );
+
+    let asset = &mut ctx.accounts.asset_account;
+
+    // Discounts come out of holder revenue, so widening them must go
+    // through the timelock
+    require!(
+        holder_privilege.is_narrowing_of(&asset.holder_privilege),
+        MarketplaceError::ChangeRequiresTimelock
+    );
+
+    asset.holder_privilege = holder_privilege;
+
+    msg!(
+        "Holder privilege for asset {}: {} bps discount from {} bps of supply",
+        asset.key(),
+        holder_privilege.discount_bps,
+        holder_privilege.threshold_bps
+    );
+
+    Ok(())
+}
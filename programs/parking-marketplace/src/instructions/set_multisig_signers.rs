@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetMultisigSigners<'info> {
+    /// Operator multisig
+    #[account(
+        mut,
+        seeds = [b"operator_multisig", operator_multisig.create_key.as_ref()],
+        bump = operator_multisig.bump,
+    )]
+    pub operator_multisig: Account<'info, OperatorMultisig>,
+
+    /// Multisig signer PDA (only signs through an executed action)
+    #[account(
+        seeds = [b"multisig_signer", operator_multisig.key().as_ref()],
+        bump = operator_multisig.signer_bump,
+    )]
+    pub multisig_signer: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMultisigSigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        OperatorMultisig::is_valid_config(&signers, threshold),
+        MarketplaceError::InvalidMultisigConfig
+    );
+
+    let multisig = &mut ctx.accounts.operator_multisig;
+
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+
+    msg!(
+        "Multisig signers updated: {} of {}",
+        threshold,
+        multisig.signers.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetProtocolPause<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.guardian == guardian.key() @ MarketplaceError::UnauthorizedGuardian,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Emergency guardian
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetProtocolPause>,
+    paused: PauseFlags,
+) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.paused = paused;
+
+    msg!(
+        "Protocol pause flags set: trading {}, distributions {}, issuance {}",
+        paused.trading,
+        paused.distributions,
+        paused.issuance
+    );
+
+    Ok(())
+}
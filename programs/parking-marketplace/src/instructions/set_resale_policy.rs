@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetResalePolicy<'info> {
+    /// Spot calendar
+    #[account(
+        mut,
+        seeds = [b"spot_calendar", asset_account.key().as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Account<'info, SpotCalendar>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetResalePolicy>,
+    resale_policy: ResalePolicy,
+) -> Result<()> {
+    require!(
+        resale_policy.is_valid(),
+        MarketplaceError::InvalidResalePolicy
+    );
+
+    let calendar = &mut ctx.accounts.spot_calendar;
+
+    calendar.resale_policy = resale_policy;
+
+    msg!(
+        "Resale policy for asset {}: cap {} bps, fee {} bps",
+        calendar.asset_account,
+        resale_policy.price_cap_bps,
+        resale_policy.fee_bps
+    );
+
+    Ok(())
+}
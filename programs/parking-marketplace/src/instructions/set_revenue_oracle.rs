@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetRevenueOracle<'info> {
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (oracle registry)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRevenueOracle>,
+    revenue_oracle: Pubkey,
+) -> Result<()> {
+    let asset = &mut ctx.accounts.asset_account;
+
+    // Turning attestation on takes effect immediately; replacing or removing
+    // an oracle weakens it and must go through the timelock
+    require!(
+        !asset.requires_revenue_attestation(),
+        MarketplaceError::ChangeRequiresTimelock
+    );
+    require!(
+        ctx.accounts.protocol_config.is_revenue_oracle(&revenue_oracle),
+        MarketplaceError::RevenueOracleNotRegistered
+    );
+    asset.revenue_oracle = revenue_oracle;
+
+    msg!(
+        "Revenue oracle for asset {} set to {}",
+        asset.key(),
+        asset.revenue_oracle
+    );
+
+    Ok(())
+}
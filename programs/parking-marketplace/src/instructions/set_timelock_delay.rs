@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MarketplaceError::UnauthorizedAdmin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetTimelockDelay>,
+    timelock_delay_seconds: i64,
+) -> Result<()> {
+    require!(
+        (ProtocolConfig::MIN_TIMELOCK_DELAY_SECONDS..=ProtocolConfig::MAX_TIMELOCK_DELAY_SECONDS)
+            .contains(&timelock_delay_seconds),
+        MarketplaceError::InvalidTimelockDelay
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.timelock_delay_seconds = timelock_delay_seconds;
+
+    msg!(
+        "Timelock delay set to {} seconds",
+        timelock_delay_seconds
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SettleTenderOffer<'info> {
+    /// Tender offer
+    #[account(
+        mut,
+        seeds = [
+            b"tender_offer",
+            asset_account.key().as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+        constraint = tender_offer.status == TenderOfferStatus::Open @ MarketplaceError::TenderOfferNotOpen,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Escrow holding tendered asset tokens
+    #[account(
+        mut,
+        seeds = [b"tender_tokens", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub token_escrow: Account<'info, TokenAccount>,
+
+    /// Bidder's asset token account (receives the tendered tokens)
+    #[account(
+        mut,
+        address = tender_offer.bidder_token_account,
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+
+    /// Parking asset being acquired
+    #[account(mut)]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Anyone may settle once the offer has expired
+    pub authority: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<SettleTenderOffer>,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.tender_offer;
+    let clock = Clock::get()?;
+
+    // Holders get the full window to tender before trading can be frozen
+    require!(
+        clock.unix_timestamp >= offer.expires_at,
+        MarketplaceError::TenderOfferNotExpired
+    );
+
+    if !offer.is_threshold_met() {
+        offer.status = TenderOfferStatus::Failed;
+
+        msg!(
+            "Tender offer failed: {} of {} tokens tendered",
+            offer.tokens_tendered,
+            offer.tokens_sought
+        );
+
+        return Ok(());
+    }
+
+    // Deliver the tendered tokens to the bidder
+    let asset_key = offer.asset_account;
+    let bidder_key = offer.bidder;
+    let seeds = &[
+        b"tender_offer",
+        asset_key.as_ref(),
+        bidder_key.as_ref(),
+        &[offer.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.token_escrow.to_account_info(),
+            to: ctx.accounts.bidder_token_account.to_account_info(),
+            authority: offer.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, ctx.accounts.token_escrow.amount)?;
+
+    offer.status = TenderOfferStatus::Settled;
+
+    // Squeeze-out: trading is frozen and remaining holders can only surrender
+    // at the offer price. Their tokens cannot be taken on chain; compelling
+    // the transfer is left to the off-chain legal process
+    let asset = &mut ctx.accounts.asset_account;
+    asset.is_tradeable = false;
+
+    msg!(
+        "Tender offer settled: {} of {} tokens acquired, remainder held for surrender",
+        offer.tokens_tendered,
+        offer.tokens_sought
+    );
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct StartChargingSession<'info> {
+    /// Charging session PDA
+    #[account(
+        init,
+        payer = driver,
+        space = ChargingSession::LEN,
+        seeds = [
+            b"charging_session",
+            station_account.key().as_ref(),
+            driver.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub session_account: Account<'info, ChargingSession>,
+
+    /// Session escrow (holds the driver's maximum payment)
+    #[account(
+        init,
+        payer = driver,
+        seeds = [b"session_escrow", session_account.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = session_account,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Charging station
+    #[account(
+        mut,
+        seeds = [b"charging_station", asset_account.key().as_ref()],
+        bump = station_account.bump,
+    )]
+    pub station_account: Account<'info, ChargingStation>,
+
+    /// Parking asset owning the station
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Asset revenue vault for the payment mint (must exist to settle)
+    #[account(
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            payment_mint.key().as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Payment token mint
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Driver's payment token account
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == driver.key(),
+        constraint = driver_payment_account.mint == payment_mint.key() @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// Driver starting the session
+    #[account(mut)]
+    pub driver: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<StartChargingSession>,
+    connector_id: u8,
+    max_amount_usdc: u64,
+) -> Result<()> {
+    let station = &mut ctx.accounts.station_account;
+    let clock = Clock::get()?;
+
+    require!(
+        station.is_connector_available(connector_id),
+        MarketplaceError::ConnectorUnavailable
+    );
+    require!(
+        max_amount_usdc >= station.rates.price_per_session_usdc && max_amount_usdc > 0,
+        MarketplaceError::InvalidPrice
+    );
+
+    station.set_connector_in_use(connector_id, true);
+
+    // Escrow the driver's maximum payment
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.driver_payment_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.driver.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, max_amount_usdc)?;
+
+    let session = &mut ctx.accounts.session_account;
+    session.station_account = ctx.accounts.station_account.key();
+    session.driver = ctx.accounts.driver.key();
+    session.connector_id = connector_id;
+    session.payment_mint = ctx.accounts.payment_mint.key();
+    session.max_amount_usdc = max_amount_usdc;
+    session.amount_charged_usdc = 0;
+    session.energy_delivered_wh = 0;
+    session.metered_by = Pubkey::default();
+    session.started_at = clock.unix_timestamp;
+    session.ended_at = 0;
+    session.status = SessionStatus::Active;
+    session.bump = ctx.bumps.session_account;
+
+    msg!(
+        "Charging session started on connector {}: {} escrowed",
+        connector_id,
+        max_amount_usdc
+    );
+
+    Ok(())
+}
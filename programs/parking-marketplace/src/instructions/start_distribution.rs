@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct StartDistribution<'info> {
+    /// Revenue distribution account
+    #[account(
+        mut,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator starting the payout
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<StartDistribution>,
+) -> Result<()> {
+    let distribution = &mut ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.distribution_status != DistributionStatus::Completed,
+        MarketplaceError::DistributionAlreadyCompleted
+    );
+    require!(
+        distribution.distribution_status == DistributionStatus::Pending,
+        MarketplaceError::InvalidDistributionStatus
+    );
+
+    // The claim window opens only once holders can actually claim
+    distribution.distribution_status = DistributionStatus::Processing;
+    distribution.claim_deadline = clock
+        .unix_timestamp
+        .checked_add(distribution.claim_window_seconds)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Revenue distribution processing: {}, claims open until {}",
+        distribution.key(),
+        distribution.claim_deadline
+    );
+
+    Ok(())
+}
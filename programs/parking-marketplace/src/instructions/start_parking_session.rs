@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct StartParkingSession<'info> {
+    /// Parking session PDA
+    #[account(
+        init,
+        payer = driver,
+        space = ParkingSession::LEN,
+        seeds = [
+            b"parking_session",
+            asset_account.key().as_ref(),
+            driver.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub session_account: Account<'info, ParkingSession>,
+
+    /// Session escrow (holds the driver's maximum payment)
+    #[account(
+        init,
+        payer = driver,
+        seeds = [b"session_escrow", session_account.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = session_account,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Parking asset (spot or lot)
+    #[account(
+        constraint = asset_account.is_active @ MarketplaceError::AssetNotActive,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Pricing schedule for the asset
+    #[account(
+        seeds = [b"pricing_schedule", asset_account.key().as_ref()],
+        bump = pricing_schedule.bump,
+    )]
+    pub pricing_schedule: Account<'info, PricingSchedule>,
+
+    /// Spot calendar (required for single spots, which may be reserved)
+    #[account(
+        seeds = [b"spot_calendar", asset_account.key().as_ref()],
+        bump = spot_calendar.bump,
+    )]
+    pub spot_calendar: Option<Account<'info, SpotCalendar>>,
+
+    /// Asset revenue vault for the payment mint (must exist to settle)
+    #[account(
+        seeds = [
+            b"revenue_vault",
+            asset_account.key().as_ref(),
+            payment_mint.key().as_ref()
+        ],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Payment token mint
+    pub payment_mint: Account<'info, Mint>,
+
+    /// Driver's payment token account
+    #[account(
+        mut,
+        constraint = driver_payment_account.owner == driver.key(),
+        constraint = driver_payment_account.mint == payment_mint.key() @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub driver_payment_account: Account<'info, TokenAccount>,
+
+    /// Driver's asset token account (optional, for holder privileges)
+    #[account(
+        constraint = holder_token_account.owner == driver.key(),
+        constraint = holder_token_account.mint == asset_account.asset_token_mint,
+    )]
+    pub holder_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Driver starting the session
+    #[account(mut)]
+    pub driver: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent sysvar
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<StartParkingSession>,
+    max_amount_usdc: u64,
+) -> Result<()> {
+    require!(max_amount_usdc > 0, MarketplaceError::InvalidPrice);
+
+    let clock = Clock::get()?;
+
+    // Walk-in sessions may not take a spot someone else has reserved
+    if ctx.accounts.asset_account.asset_type == AssetType::SingleSpot {
+        let calendar = ctx
+            .accounts
+            .spot_calendar
+            .as_ref()
+            .ok_or(MarketplaceError::SpotCalendarRequired)?;
+        require!(
+            !calendar.is_booked_by_other(&ctx.accounts.driver.key(), clock.unix_timestamp),
+            MarketplaceError::SpotUnavailable
+        );
+    }
+
+    // Escrow the driver's maximum payment
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.driver_payment_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.driver.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, max_amount_usdc)?;
+
+    let session = &mut ctx.accounts.session_account;
+    session.asset_account = ctx.accounts.asset_account.key();
+    session.driver = ctx.accounts.driver.key();
+    session.payment_mint = ctx.accounts.payment_mint.key();
+    session.max_amount_usdc = max_amount_usdc;
+    session.amount_charged_usdc = 0;
+    session.started_at = clock.unix_timestamp;
+    session.ended_at = 0;
+    session.status = SessionStatus::Active;
+
+    // Lock in holder discount eligibility now; settlement only pays the
+    // discount on the part of this balance the same account still holds.
+    // The balance is checked at start and end only, so tokens lent to
+    // another driver in between can qualify both sessions
+    let asset = &ctx.accounts.asset_account;
+    match &ctx.accounts.holder_token_account {
+        Some(account) if asset.grants_holder_discount(account.amount) => {
+            session.holder_token_account = account.key();
+            session.qualifying_balance = account.amount;
+        }
+        _ => {
+            session.holder_token_account = Pubkey::default();
+            session.qualifying_balance = 0;
+        }
+    }
+    session.bump = ctx.bumps.session_account;
+
+    msg!(
+        "Parking session started: asset {}, {} escrowed",
+        session.asset_account,
+        max_amount_usdc
+    );
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SurrenderTokens<'info> {
+    /// Settled tender offer
+    #[account(
+        mut,
+        seeds = [
+            b"tender_offer",
+            tender_offer.asset_account.as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+        constraint = tender_offer.status == TenderOfferStatus::Settled @ MarketplaceError::TenderOfferNotSettled,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Parking asset the tender offer is for
+    #[account(
+        address = tender_offer.asset_account,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Payment escrow (holds funds for late holders)
+    #[account(
+        mut,
+        seeds = [b"tender_escrow", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub payment_escrow: Account<'info, TokenAccount>,
+
+    /// Bidder's asset token account (receives the surrendered tokens)
+    #[account(
+        mut,
+        address = tender_offer.bidder_token_account,
+    )]
+    pub bidder_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's asset token account
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == bidder_token_account.mint,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's payment token account
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == tender_offer.payment_mint @ MarketplaceError::PaymentMethodNotAccepted,
+    )]
+    pub holder_payment_account: Account<'info, TokenAccount>,
+
+    /// Late holder surrendering tokens
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<SurrenderTokens>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let offer = &mut ctx.accounts.tender_offer;
+
+    // The escrow only covers tokens the bidder did not already hold
+    require!(
+        ctx.accounts.holder.key() != offer.bidder
+            && ctx.accounts.holder_token_account.key() != offer.bidder_token_account,
+        MarketplaceError::BidderCannotTender
+    );
+
+    let payment_usdc = offer
+        .payment_for(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            to: ctx.accounts.bidder_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let asset_key = offer.asset_account;
+    let bidder_key = offer.bidder;
+    let seeds = &[
+        b"tender_offer",
+        asset_key.as_ref(),
+        bidder_key.as_ref(),
+        &[offer.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payment_escrow.to_account_info(),
+            to: ctx.accounts.holder_payment_account.to_account_info(),
+            authority: offer.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, payment_usdc)?;
+
+    offer.tokens_surrendered = offer
+        .tokens_surrendered
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    msg!(
+        "Surrendered {} tokens for {}",
+        amount,
+        payment_usdc
+    );
+
+    Ok(())
+}
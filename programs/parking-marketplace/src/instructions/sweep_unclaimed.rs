@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    /// Revenue distribution account (closed, rent returned to operator)
+    #[account(
+        mut,
+        close = operator,
+        constraint = distribution_account.asset_account == asset_account.key() @ MarketplaceError::InvalidDistributionStatus,
+    )]
+    pub distribution_account: Account<'info, RevenueDistribution>,
+
+    /// Parking asset
+    #[account(
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+        constraint = !asset_account.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Distribution vault (closed after sweeping)
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution_account.key().as_ref()],
+        bump,
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Token account receiving the leftover funds
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == distribution_account.payout_mint @ MarketplaceError::PayoutMintNotAccepted,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    /// Next pending distribution (required when sweeping to the next period)
+    #[account(mut)]
+    pub next_distribution_account: Option<Account<'info, RevenueDistribution>>,
+
+    /// Protocol config (reserve fund, pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.distributions @ MarketplaceError::DistributionsPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Operator sweeping the distribution
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<SweepUnclaimed>,
+    destination: SweepDestination,
+) -> Result<()> {
+    let distribution = &ctx.accounts.distribution_account;
+    let clock = Clock::get()?;
+
+    require!(
+        distribution.is_sweepable(clock.unix_timestamp),
+        MarketplaceError::ClaimWindowOpen
+    );
+
+    let leftover = ctx.accounts.distribution_vault.amount;
+    let destination_account = &ctx.accounts.destination_token_account;
+
+    // Validate the destination
+    match destination {
+        SweepDestination::Operator => {
+            // Holder money from the revenue vault never goes back to the operator
+            require!(
+                destination_account.owner == ctx.accounts.operator.key()
+                    && distribution.holder_funds_in(leftover) == 0,
+                MarketplaceError::InvalidSweepDestination
+            );
+        }
+        SweepDestination::ReserveFund => {
+            require!(
+                destination_account.owner == ctx.accounts.protocol_config.reserve_fund,
+                MarketplaceError::InvalidSweepDestination
+            );
+        }
+        SweepDestination::NextPeriod => {
+            let next = ctx
+                .accounts
+                .next_distribution_account
+                .as_mut()
+                .ok_or(MarketplaceError::InvalidSweepDestination)?;
+
+            require!(
+                next.key() != distribution.key()
+                    && next.asset_account == distribution.asset_account
+                    && next.payout_mint == distribution.payout_mint
+                    && next.created_at > distribution.created_at,
+                MarketplaceError::InvalidSweepDestination
+            );
+            require!(
+                next.distribution_status == DistributionStatus::Pending,
+                MarketplaceError::InvalidDistributionStatus
+            );
+
+            let (next_vault, _) = Pubkey::find_program_address(
+                &[b"distribution_vault", next.key().as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                destination_account.key() == next_vault,
+                MarketplaceError::InvalidSweepDestination
+            );
+
+            // Leftovers raise the next period's payout per token
+            let holder_funds = distribution.holder_funds_in(leftover);
+            next.vault_funded_usdc = next
+                .vault_funded_usdc
+                .checked_add(holder_funds)
+                .ok_or(MarketplaceError::ArithmeticOverflow)?;
+            next.operator_funded_usdc = next
+                .operator_funded_usdc
+                .checked_add(leftover - holder_funds)
+                .ok_or(MarketplaceError::ArithmeticOverflow)?;
+            next.net_revenue_usdc = next
+                .net_revenue_usdc
+                .checked_add(leftover)
+                .ok_or(MarketplaceError::ArithmeticOverflow)?;
+            next.calculate_revenue_per_token();
+        }
+    }
+
+    let asset_key = distribution.asset_account;
+    let created_at_bytes = distribution.created_at.to_le_bytes();
+    let seeds = &[
+        b"revenue_distribution",
+        asset_key.as_ref(),
+        created_at_bytes.as_ref(),
+        &[distribution.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Move leftovers out of the vault
+    if leftover > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: distribution.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, leftover)?;
+    }
+
+    // Close the vault and recover rent
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.distribution_vault.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: distribution.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    msg!(
+        "Unclaimed revenue swept: {} tokens of mint {} from distribution {}",
+        leftover,
+        distribution.payout_mint,
+        distribution.key()
+    );
+
+    Ok(())
+}
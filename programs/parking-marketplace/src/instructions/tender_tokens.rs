@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct TenderTokens<'info> {
+    /// Tender offer
+    #[account(
+        mut,
+        seeds = [
+            b"tender_offer",
+            tender_offer.asset_account.as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Parking asset the tender offer is for
+    #[account(
+        address = tender_offer.asset_account,
+        constraint = !asset_account.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Protocol config (pause flags)
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused.trading @ MarketplaceError::TradingPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Tender position PDA (one per holder)
+    #[account(
+        init,
+        payer = holder,
+        space = TenderPosition::LEN,
+        seeds = [
+            b"tender_position",
+            tender_offer.key().as_ref(),
+            holder.key().as_ref()
+        ],
+        bump
+    )]
+    pub tender_position: Account<'info, TenderPosition>,
+
+    /// Escrow holding tendered asset tokens
+    #[account(
+        mut,
+        seeds = [b"tender_tokens", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub token_escrow: Account<'info, TokenAccount>,
+
+    /// Holder's asset token account
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == token_escrow.mint,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Token holder
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<TenderTokens>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let offer = &mut ctx.accounts.tender_offer;
+    let clock = Clock::get()?;
+
+    require!(
+        offer.is_open(clock.unix_timestamp),
+        MarketplaceError::TenderOfferNotOpen
+    );
+    // The bidder cannot tender to itself to reach the threshold
+    require!(
+        ctx.accounts.holder.key() != offer.bidder
+            && ctx.accounts.holder_token_account.key() != offer.bidder_token_account,
+        MarketplaceError::BidderCannotTender
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            to: ctx.accounts.token_escrow.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    offer.tokens_tendered = offer
+        .tokens_tendered
+        .checked_add(amount)
+        .ok_or(MarketplaceError::ArithmeticOverflow)?;
+
+    let position = &mut ctx.accounts.tender_position;
+    position.tender_offer = offer.key();
+    position.holder = ctx.accounts.holder.key();
+    position.amount = amount;
+    position.tendered_at = clock.unix_timestamp;
+    position.bump = ctx.bumps.tender_position;
+
+    msg!(
+        "Tendered {} tokens ({} of {} sought)",
+        amount,
+        offer.tokens_tendered,
+        offer.tokens_sought
+    );
+
+    Ok(())
+}
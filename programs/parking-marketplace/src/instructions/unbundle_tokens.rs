@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct UnbundleTokens<'info> {
+    /// Bundle composition
+    #[account(
+        seeds = [b"asset_bundle", bundle_asset.key().as_ref()],
+        bump = asset_bundle.bump,
+    )]
+    pub asset_bundle: Account<'info, AssetBundle>,
+
+    /// Bundle asset (vault authority)
+    #[account(mut)]
+    pub bundle_asset: Account<'info, ParkingAsset>,
+
+    /// CHECK: Bundle token mint, validated against the bundle asset
+    #[account(
+        mut,
+        address = bundle_asset.asset_token_mint,
+    )]
+    pub bundle_mint: UncheckedAccount<'info>,
+
+    /// Holder's bundle token account (bundle tokens are burned from it)
+    #[account(
+        mut,
+        constraint = holder_bundle_account.owner == holder.key(),
+        constraint = holder_bundle_account.mint == bundle_asset.asset_token_mint,
+    )]
+    pub holder_bundle_account: Account<'info, TokenAccount>,
+
+    /// Bundle token holder
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    // remaining_accounts: for each component in order,
+    // [component vault (mut), holder component token account (mut)]
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UnbundleTokens<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, MarketplaceError::InvalidTokenAmount);
+
+    let bundle = &ctx.accounts.asset_bundle;
+    let asset = &ctx.accounts.bundle_asset;
+    require!(
+        amount <= asset.circulating_supply,
+        MarketplaceError::InsufficientBalance
+    );
+    require!(
+        ctx.remaining_accounts.len() == bundle.components.len() * 2,
+        MarketplaceError::InvalidBundleAccounts
+    );
+
+    // Burn the bundle tokens first
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.bundle_mint.to_account_info(),
+            from: ctx.accounts.holder_bundle_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::burn(burn_ctx, amount)?;
+
+    let lot_id_bytes = asset.parking_lot_id.to_le_bytes();
+    let seeds = &[
+        b"parking_asset",
+        lot_id_bytes.as_ref(),
+        asset.spot_number.as_bytes(),
+        &[asset.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Redeem each constituent pro rata to the bundle supply
+    for (component, accounts) in bundle
+        .components
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(2))
+    {
+        require!(
+            accounts[0].key() == component.vault,
+            MarketplaceError::InvalidBundleAccounts
+        );
+        let vault = Account::<TokenAccount>::try_from(&accounts[0])?;
+        let destination = Account::<TokenAccount>::try_from(&accounts[1])?;
+        require!(
+            destination.mint == component.asset_token_mint
+                && destination.owner == ctx.accounts.holder.key(),
+            MarketplaceError::InvalidBundleAccounts
+        );
+
+        let units = ((vault.amount as u128 * amount as u128)
+            / asset.circulating_supply as u128) as u64;
+        if units == 0 {
+            continue;
+        }
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts[0].clone(),
+                to: accounts[1].clone(),
+                authority: asset.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, units)?;
+    }
+
+    let asset = &mut ctx.accounts.bundle_asset;
+    asset.total_supply = asset.total_supply.saturating_sub(amount);
+    asset.circulating_supply = asset.circulating_supply.saturating_sub(amount);
+
+    msg!("Unbundled {} tokens of {}", amount, asset.spot_number);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct UpdateCostCaps<'info> {
+    /// Parking asset
+    #[account(
+        mut,
+        constraint = asset_account.institutional_operator == operator.key() @ MarketplaceError::UnauthorizedOperator,
+    )]
+    pub asset_account: Account<'info, ParkingAsset>,
+
+    /// Operator (must be asset owner)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCostCaps>,
+    cost_caps: OperatingCostCaps,
+) -> Result<()> {
+    require!(cost_caps.is_valid(), MarketplaceError::InvalidCostCaps);
+
+    let asset = &mut ctx.accounts.asset_account;
+
+    // Raising a cap hurts holders and must go through the timelock
+    require!(
+        cost_caps.is_decrease_from(&asset.cost_caps),
+        MarketplaceError::ChangeRequiresTimelock
+    );
+
+    asset.cost_caps = cost_caps;
+
+    msg!("Operating cost caps updated for asset {}", asset.key());
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct UpdatePayoutMints<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MarketplaceError::UnauthorizedAdmin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePayoutMints>,
+    accepted_payout_mints: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        accepted_payout_mints.len() <= ProtocolConfig::MAX_PAYOUT_MINTS,
+        MarketplaceError::TooManyPayoutMints
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.accepted_payout_mints = accepted_payout_mints;
+
+    msg!(
+        "Accepted payout mints updated: {} mints",
+        config.accepted_payout_mints.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct UpdateRevenueOracles<'info> {
+    /// Protocol config PDA
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MarketplaceError::UnauthorizedAdmin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol administrator
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateRevenueOracles>,
+    revenue_oracles: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        revenue_oracles.len() <= ProtocolConfig::MAX_REVENUE_ORACLES,
+        MarketplaceError::TooManyRevenueOracles
+    );
+
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.revenue_oracles = revenue_oracles;
+
+    msg!(
+        "Registered revenue oracles updated: {} oracles",
+        config.revenue_oracles.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::error::MarketplaceError;
+
+#[derive(Accounts)]
+pub struct WithdrawTender<'info> {
+    /// Tender offer
+    #[account(
+        mut,
+        seeds = [
+            b"tender_offer",
+            tender_offer.asset_account.as_ref(),
+            tender_offer.bidder.as_ref()
+        ],
+        bump = tender_offer.bump,
+    )]
+    pub tender_offer: Account<'info, TenderOffer>,
+
+    /// Tender position (closed, rent returned to holder)
+    #[account(
+        mut,
+        close = holder,
+        seeds = [
+            b"tender_position",
+            tender_offer.key().as_ref(),
+            holder.key().as_ref()
+        ],
+        bump = tender_position.bump,
+    )]
+    pub tender_position: Account<'info, TenderPosition>,
+
+    /// Escrow holding tendered asset tokens
+    #[account(
+        mut,
+        seeds = [b"tender_tokens", tender_offer.key().as_ref()],
+        bump,
+    )]
+    pub token_escrow: Account<'info, TokenAccount>,
+
+    /// Holder's asset token account (receives the tokens back)
+    #[account(
+        mut,
+        constraint = holder_token_account.owner == holder.key(),
+        constraint = holder_token_account.mint == token_escrow.mint,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Token holder
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawTender>,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.tender_offer;
+    let amount = ctx.accounts.tender_position.amount;
+
+    // Tenders can be pulled until the offer settles
+    require!(
+        offer.status != TenderOfferStatus::Settled,
+        MarketplaceError::TenderOfferNotOpen
+    );
+
+    let asset_key = offer.asset_account;
+    let bidder_key = offer.bidder;
+    let seeds = &[
+        b"tender_offer",
+        asset_key.as_ref(),
+        bidder_key.as_ref(),
+        &[offer.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.token_escrow.to_account_info(),
+            to: ctx.accounts.holder_token_account.to_account_info(),
+            authority: offer.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    offer.tokens_tendered = offer.tokens_tendered.saturating_sub(amount);
+
+    msg!("Tender of {} tokens withdrawn", amount);
+
+    Ok(())
+}
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod error;
+pub mod verification;
+pub mod pricing;
 
 use instructions::*;
 use state::*;
@@ -15,15 +17,18 @@ pub mod parking_marketplace {
 
     /// Initialize a tokenized parking asset
     /// Creates an SPL token mint and associates it with parking infrastructure
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_asset(
         ctx: Context<InitializeAsset>,
         parking_lot_id: u64,
         spot_number: String,
         asset_type: AssetType,
         total_supply: u64,
+        max_supply: u64,
         estimated_value_usdc: u64,
         annual_revenue_usdc: u64,
         revenue_share_percentage: u16,
+        cost_caps: OperatingCostCaps,
     ) -> Result<()> {
         instructions::initialize_asset::handler(
             ctx,
@@ -31,9 +36,11 @@ pub mod parking_marketplace {
             spot_number,
             asset_type,
             total_supply,
+            max_supply,
             estimated_value_usdc,
             annual_revenue_usdc,
             revenue_share_percentage,
+            cost_caps,
         )
     }
 
@@ -71,17 +78,21 @@ pub mod parking_marketplace {
     /// Distribute revenue to parking asset token holders
     pub fn distribute_revenue(
         ctx: Context<DistributeRevenue>,
-        total_revenue_usdc: u64,
-        operating_costs_usdc: u64,
+        operating_costs: OperatingCosts,
         period_start: i64,
         period_end: i64,
+        declared_gap: bool,
+        claim_window_seconds: i64,
+        holder_snapshot: HolderSnapshot,
     ) -> Result<()> {
         instructions::distribute_revenue::handler(
             ctx,
-            total_revenue_usdc,
-            operating_costs_usdc,
+            operating_costs,
             period_start,
             period_end,
+            declared_gap,
+            claim_window_seconds,
+            holder_snapshot,
         )
     }
 
@@ -99,4 +110,724 @@ pub mod parking_marketplace {
     ) -> Result<()> {
         instructions::cancel_listing::handler(ctx)
     }
+
+    /// Begin paying out a pending revenue distribution
+    pub fn start_distribution(
+        ctx: Context<StartDistribution>,
+    ) -> Result<()> {
+        instructions::start_distribution::handler(ctx)
+    }
+
+    /// Reconcile a distribution vault's balance against recorded payouts
+    pub fn reconcile_distribution(
+        ctx: Context<ReconcileDistribution>,
+    ) -> Result<()> {
+        instructions::reconcile_distribution::handler(ctx)
+    }
+
+    /// Complete a distribution once all claims are paid or the claim window closes
+    pub fn finalize_distribution(
+        ctx: Context<FinalizeDistribution>,
+    ) -> Result<()> {
+        instructions::finalize_distribution::handler(ctx)
+    }
+
+    /// Mark a revenue distribution as failed with a reason code
+    pub fn fail_distribution(
+        ctx: Context<FailDistribution>,
+        reason: DistributionFailureReason,
+    ) -> Result<()> {
+        instructions::fail_distribution::handler(ctx, reason)
+    }
+
+    /// Initialize protocol-wide configuration
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        accepted_payout_mints: Vec<Pubkey>,
+        reserve_fund: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_protocol_config::handler(
+            ctx,
+            accepted_payout_mints,
+            reserve_fund,
+        )
+    }
+
+    /// Replace the allowlist of revenue payout mints
+    pub fn update_payout_mints(
+        ctx: Context<UpdatePayoutMints>,
+        accepted_payout_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_payout_mints::handler(ctx, accepted_payout_mints)
+    }
+
+    /// Deposit payout tokens into a distribution vault
+    pub fn fund_distribution(
+        ctx: Context<FundDistribution>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_distribution::handler(ctx, amount)
+    }
+
+    /// Claim a holder's share of a revenue distribution from its balance snapshot
+    pub fn claim_revenue(
+        ctx: Context<ClaimRevenue>,
+        token_balance: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_revenue::handler(ctx, token_balance, proof)
+    }
+
+    /// Sweep unclaimed revenue after the claim window and close the distribution
+    pub fn sweep_unclaimed(
+        ctx: Context<SweepUnclaimed>,
+        destination: SweepDestination,
+    ) -> Result<()> {
+        instructions::sweep_unclaimed::handler(ctx, destination)
+    }
+
+    /// Replace the registry of revenue oracle keys
+    pub fn update_revenue_oracles(
+        ctx: Context<UpdateRevenueOracles>,
+        revenue_oracles: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_revenue_oracles::handler(ctx, revenue_oracles)
+    }
+
+    /// Start requiring signed revenue reports for an asset (changes go through the timelock)
+    pub fn set_revenue_oracle(
+        ctx: Context<SetRevenueOracle>,
+        revenue_oracle: Pubkey,
+    ) -> Result<()> {
+        instructions::set_revenue_oracle::handler(ctx, revenue_oracle)
+    }
+
+    /// Lower per-category operating cost caps for an asset (raises go through the timelock)
+    pub fn update_cost_caps(
+        ctx: Context<UpdateCostCaps>,
+        cost_caps: OperatingCostCaps,
+    ) -> Result<()> {
+        instructions::update_cost_caps::handler(ctx, cost_caps)
+    }
+
+    /// Create the revenue vault collecting an asset's payments in one mint
+    pub fn initialize_revenue_vault(
+        ctx: Context<InitializeRevenueVault>,
+    ) -> Result<()> {
+        instructions::initialize_revenue_vault::handler(ctx)
+    }
+
+    /// Deposit a parking or charging payment into an asset's revenue vault
+    pub fn deposit_revenue(
+        ctx: Context<DepositRevenue>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_revenue::handler(ctx, amount)
+    }
+
+    /// Create the booking calendar for a tokenized parking spot
+    pub fn initialize_spot_calendar(
+        ctx: Context<InitializeSpotCalendar>,
+    ) -> Result<()> {
+        instructions::initialize_spot_calendar::handler(ctx)
+    }
+
+    /// Reserve a tokenized parking spot for a time window
+    pub fn reserve_spot(
+        ctx: Context<ReserveSpot>,
+        start_time: i64,
+        end_time: i64,
+        max_price_usdc: u64,
+    ) -> Result<()> {
+        instructions::reserve_spot::handler(ctx, start_time, end_time, max_price_usdc)
+    }
+
+    /// Create the usage pricing schedule for a spot or lot
+    pub fn initialize_pricing_schedule(
+        ctx: Context<InitializePricingSchedule>,
+        rates: PricingRates,
+    ) -> Result<()> {
+        instructions::initialize_pricing_schedule::handler(ctx, rates)
+    }
+
+    /// Start a pay-as-you-go parking session, escrowing a maximum amount
+    pub fn start_parking_session(
+        ctx: Context<StartParkingSession>,
+        max_amount_usdc: u64,
+    ) -> Result<()> {
+        instructions::start_parking_session::handler(ctx, max_amount_usdc)
+    }
+
+    /// End a parking session, paying the asset and refunding the driver
+    pub fn end_parking_session(
+        ctx: Context<EndParkingSession>,
+    ) -> Result<()> {
+        instructions::end_parking_session::handler(ctx)
+    }
+
+    /// Narrow usage discounts for holders of a parking spot's tokens
+    pub fn set_holder_privilege(
+        ctx: Context<SetHolderPrivilege>,
+        holder_privilege: HolderPrivilege,
+    ) -> Result<()> {
+        instructions::set_holder_privilege::handler(ctx, holder_privilege)
+    }
+
+    /// Register EV charging station parameters for a ChargingStation asset
+    pub fn initialize_charging_station(
+        ctx: Context<InitializeChargingStation>,
+        station_id: u64,
+        charger_type: ChargerType,
+        total_connectors: u8,
+        max_power_kw: u32,
+        rates: ChargingRates,
+    ) -> Result<()> {
+        instructions::initialize_charging_station::handler(
+            ctx,
+            station_id,
+            charger_type,
+            total_connectors,
+            max_power_kw,
+            rates,
+        )
+    }
+
+    /// Start an EV charging session, escrowing a maximum amount
+    pub fn start_charging_session(
+        ctx: Context<StartChargingSession>,
+        connector_id: u8,
+        max_amount_usdc: u64,
+    ) -> Result<()> {
+        instructions::start_charging_session::handler(ctx, connector_id, max_amount_usdc)
+    }
+
+    /// End an EV charging session with a signed meter reading and settle payment
+    pub fn end_charging_session(
+        ctx: Context<EndChargingSession>,
+        energy_delivered_wh: u64,
+        reading_timestamp: i64,
+        device_key: Pubkey,
+    ) -> Result<()> {
+        instructions::end_charging_session::handler(
+            ctx,
+            energy_delivered_wh,
+            reading_timestamp,
+            device_key,
+        )
+    }
+
+    /// Register a charger device key that signs meter readings
+    pub fn register_charger_device(
+        ctx: Context<RegisterChargerDevice>,
+        device_key: Pubkey,
+        connector_id: u8,
+    ) -> Result<()> {
+        instructions::register_charger_device::handler(ctx, device_key, connector_id)
+    }
+
+    /// Remove a charger device key from a station
+    pub fn remove_charger_device(
+        ctx: Context<RemoveChargerDevice>,
+        device_key: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_charger_device::handler(ctx, device_key)
+    }
+
+    /// Offer subscription passes for a parking lot tier
+    pub fn create_subscription_offer(
+        ctx: Context<CreateSubscriptionOffer>,
+        tier: u8,
+        price_usdc: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::create_subscription_offer::handler(ctx, tier, price_usdc, duration_seconds)
+    }
+
+    /// Buy a subscription pass, minted as a transferable non-fungible token
+    pub fn purchase_subscription(
+        ctx: Context<PurchaseSubscription>,
+        valid_from: i64,
+    ) -> Result<()> {
+        instructions::purchase_subscription::handler(ctx, valid_from)
+    }
+
+    /// Extend a subscription pass by one period
+    pub fn renew_subscription(
+        ctx: Context<RenewSubscription>,
+    ) -> Result<()> {
+        instructions::renew_subscription::handler(ctx)
+    }
+
+    /// Set resale price caps and fees for a spot's reservations
+    pub fn set_resale_policy(
+        ctx: Context<SetResalePolicy>,
+        resale_policy: ResalePolicy,
+    ) -> Result<()> {
+        instructions::set_resale_policy::handler(ctx, resale_policy)
+    }
+
+    /// List a reservation for resale on the marketplace
+    pub fn list_reservation(
+        ctx: Context<ListReservation>,
+        price_usdc: u64,
+        expires_in_seconds: i64,
+    ) -> Result<()> {
+        instructions::list_reservation::handler(ctx, price_usdc, expires_in_seconds)
+    }
+
+    /// Buy a reservation from a marketplace listing
+    pub fn buy_reservation(
+        ctx: Context<BuyReservation>,
+    ) -> Result<()> {
+        instructions::buy_reservation::handler(ctx)
+    }
+
+    /// Create a parking lot bundle asset backed by SingleSpot assets
+    pub fn initialize_bundle(
+        ctx: Context<InitializeBundle>,
+        parking_lot_id: u64,
+        spot_number: String,
+    ) -> Result<()> {
+        instructions::initialize_bundle::handler(ctx, parking_lot_id, spot_number)
+    }
+
+    /// Add a SingleSpot constituent to a bundle before any tokens are issued
+    pub fn add_bundle_component(
+        ctx: Context<AddBundleComponent>,
+        units_per_bundle: u64,
+    ) -> Result<()> {
+        instructions::add_bundle_component::handler(ctx, units_per_bundle)
+    }
+
+    /// Deposit constituent tokens and receive bundle tokens
+    pub fn bundle_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BundleTokens<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::bundle_tokens::handler(ctx, amount)
+    }
+
+    /// Burn bundle tokens and redeem constituents pro rata
+    pub fn unbundle_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UnbundleTokens<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unbundle_tokens::handler(ctx, amount)
+    }
+
+    /// Claim a constituent distribution into the bundle's revenue vault
+    pub fn collect_bundle_revenue(
+        ctx: Context<CollectBundleRevenue>,
+        token_balance: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::collect_bundle_revenue::handler(ctx, token_balance, proof)
+    }
+
+    /// Create an index fund with its own index token mint
+    pub fn initialize_index_fund(
+        ctx: Context<InitializeIndexFund>,
+        band_bps: u16,
+        min_rebalance_interval: i64,
+    ) -> Result<()> {
+        instructions::initialize_index_fund::handler(ctx, band_bps, min_rebalance_interval)
+    }
+
+    /// Add a weighted parking asset position before index tokens are issued
+    pub fn add_index_position(
+        ctx: Context<AddIndexPosition>,
+        target_weight_bps: u16,
+    ) -> Result<()> {
+        instructions::add_index_position::handler(ctx, target_weight_bps)
+    }
+
+    /// Deposit a pro-rata basket of every position and mint index tokens
+    pub fn mint_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintIndex<'info>>,
+        index_amount: u64,
+    ) -> Result<()> {
+        instructions::mint_index::handler(ctx, index_amount)
+    }
+
+    /// Burn index tokens and redeem a pro-rata basket of every position
+    pub fn redeem_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemIndex<'info>>,
+        index_amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_index::handler(ctx, index_amount)
+    }
+
+    /// Swap an overweight position for an underweight one within weight bands
+    pub fn rebalance_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RebalanceIndex<'info>>,
+        sell_index: u8,
+        buy_index: u8,
+        amount_in: u64,
+    ) -> Result<()> {
+        instructions::rebalance_index::handler(ctx, sell_index, buy_index, amount_in)
+    }
+
+    /// Open a funded operator buyback at a published price per token
+    pub fn open_buyback(
+        ctx: Context<OpenBuyback>,
+        price_per_token_usdc: u64,
+        max_tokens: u64,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::open_buyback::handler(ctx, price_per_token_usdc, max_tokens, duration_seconds)
+    }
+
+    /// Sell tokens into the buyback; tokens are burned
+    pub fn redeem_tokens(
+        ctx: Context<RedeemTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_tokens::handler(ctx, amount)
+    }
+
+    /// Close an expired or exhausted buyback and refund unused funds
+    pub fn close_buyback(
+        ctx: Context<CloseBuyback>,
+    ) -> Result<()> {
+        instructions::close_buyback::handler(ctx)
+    }
+
+    /// Announce a follow-on issuance from timelock-authorized supply
+    pub fn issue_additional_tokens(
+        ctx: Context<IssueAdditionalTokens>,
+        amount: u64,
+        price_per_token_usdc: u64,
+        preemption_seconds: i64,
+        sale_seconds: i64,
+        holder_snapshot: HolderSnapshot,
+    ) -> Result<()> {
+        instructions::issue_additional_tokens::handler(
+            ctx,
+            amount,
+            price_per_token_usdc,
+            preemption_seconds,
+            sale_seconds,
+            holder_snapshot,
+        )
+    }
+
+    /// Buy a pro rata share of an issuance during the pre-emption window
+    pub fn exercise_preemption(
+        ctx: Context<ExercisePreemption>,
+        amount: u64,
+        snapshot_balance: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::exercise_preemption::handler(ctx, amount, snapshot_balance, proof)
+    }
+
+    /// Buy issuance tokens during the public sale
+    pub fn subscribe_issuance(
+        ctx: Context<SubscribeIssuance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::subscribe_issuance::handler(ctx, amount)
+    }
+
+    /// Set or clear the authority that must approve operator transfers
+    pub fn set_compliance_authority(
+        ctx: Context<SetComplianceAuthority>,
+        compliance_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_compliance_authority::handler(ctx, compliance_authority)
+    }
+
+    /// Propose handing a parking lot's assets to a new operator
+    pub fn propose_operator_transfer(
+        ctx: Context<ProposeOperatorTransfer>,
+        parking_lot_id: u64,
+        proposed_operator: Pubkey,
+        asset_count: u32,
+    ) -> Result<()> {
+        instructions::propose_operator_transfer::handler(
+            ctx,
+            parking_lot_id,
+            proposed_operator,
+            asset_count,
+        )
+    }
+
+    /// Approve a pending operator transfer as compliance authority
+    pub fn approve_operator_transfer(
+        ctx: Context<ApproveOperatorTransfer>,
+    ) -> Result<()> {
+        instructions::approve_operator_transfer::handler(ctx)
+    }
+
+    /// Accept an operator transfer, taking over a batch of the lot's assets
+    pub fn accept_operator_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptOperatorTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::accept_operator_transfer::handler(ctx)
+    }
+
+    /// Cancel a pending operator transfer
+    pub fn cancel_operator_transfer(
+        ctx: Context<CancelOperatorTransfer>,
+    ) -> Result<()> {
+        instructions::cancel_operator_transfer::handler(ctx)
+    }
+
+    /// Create an M-of-N multisig whose signer PDA can act as an operator
+    pub fn create_operator_multisig(
+        ctx: Context<CreateOperatorMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_operator_multisig::handler(ctx, signers, threshold)
+    }
+
+    /// Replace the multisig's signers and threshold (via an executed action)
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig_signers::handler(ctx, signers, threshold)
+    }
+
+    /// Propose a marketplace instruction for multisig approval
+    pub fn propose_action(
+        ctx: Context<ProposeAction>,
+        accounts: Vec<ActionAccount>,
+        data: Vec<u8>,
+        expires_in_seconds: i64,
+    ) -> Result<()> {
+        instructions::propose_action::handler(ctx, accounts, data, expires_in_seconds)
+    }
+
+    /// Approve a pending multisig action
+    pub fn approve_action(
+        ctx: Context<ApproveAction>,
+    ) -> Result<()> {
+        instructions::approve_action::handler(ctx)
+    }
+
+    /// Execute a pending action once the approval threshold is met
+    pub fn execute_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteAction<'info>>,
+    ) -> Result<()> {
+        instructions::execute_action::handler(ctx)
+    }
+
+    /// Close a pending action that expired without executing
+    pub fn close_expired_action(
+        ctx: Context<CloseExpiredAction>,
+    ) -> Result<()> {
+        instructions::close_expired_action::handler(ctx)
+    }
+
+    /// Set the delay applied to sensitive asset changes
+    pub fn set_timelock_delay(
+        ctx: Context<SetTimelockDelay>,
+        timelock_delay_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_timelock_delay::handler(ctx, timelock_delay_seconds)
+    }
+
+    /// Schedule a sensitive asset change behind the timelock
+    pub fn schedule_asset_change(
+        ctx: Context<ScheduleAssetChange>,
+        change: AssetChange,
+    ) -> Result<()> {
+        instructions::schedule_asset_change::handler(ctx, change)
+    }
+
+    /// Cancel a scheduled asset change (current operator or original scheduler)
+    pub fn cancel_asset_change(
+        ctx: Context<CancelAssetChange>,
+    ) -> Result<()> {
+        instructions::cancel_asset_change::handler(ctx)
+    }
+
+    /// Apply a scheduled asset change once its delay has elapsed
+    pub fn execute_asset_change(
+        ctx: Context<ExecuteAssetChange>,
+    ) -> Result<()> {
+        instructions::execute_asset_change::handler(ctx)
+    }
+
+    /// Set the emergency guardian (must differ from the admin)
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /// Pause or resume instruction groups protocol-wide
+    pub fn set_protocol_pause(
+        ctx: Context<SetProtocolPause>,
+        paused: PauseFlags,
+    ) -> Result<()> {
+        instructions::set_protocol_pause::handler(ctx, paused)
+    }
+
+    /// Pause or resume instruction groups for a single asset
+    pub fn set_asset_pause(
+        ctx: Context<SetAssetPause>,
+        paused: PauseFlags,
+    ) -> Result<()> {
+        instructions::set_asset_pause::handler(ctx, paused)
+    }
+
+    /// Begin winding down an asset with approval: halt trading and await sale proceeds
+    pub fn decommission_asset(
+        ctx: Context<DecommissionAsset>,
+        redemption_window_seconds: i64,
+        unclaimed_destination: SweepDestination,
+    ) -> Result<()> {
+        instructions::decommission_asset::handler(
+            ctx,
+            redemption_window_seconds,
+            unclaimed_destination,
+        )
+    }
+
+    /// Deposit sale proceeds for a decommissioned asset
+    pub fn deposit_sale_proceeds(
+        ctx: Context<DepositSaleProceeds>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_sale_proceeds::handler(ctx, amount)
+    }
+
+    /// Burn tokens of a decommissioned asset for a pro-rata share of the proceeds
+    pub fn redeem_final(
+        ctx: Context<RedeemFinal>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::redeem_final::handler(ctx, amount)
+    }
+
+    /// Close a decommissioned asset once the redemption window has ended
+    pub fn close_decommission(
+        ctx: Context<CloseDecommission>,
+    ) -> Result<()> {
+        instructions::close_decommission::handler(ctx)
+    }
+
+    /// Escrow funds for a tender offer on all outstanding tokens the bidder does not hold
+    pub fn create_tender_offer(
+        ctx: Context<CreateTenderOffer>,
+        price_per_token_usdc: u64,
+        threshold_bps: u16,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        instructions::create_tender_offer::handler(
+            ctx,
+            price_per_token_usdc,
+            threshold_bps,
+            duration_seconds,
+        )
+    }
+
+    /// Tender tokens into an open offer
+    pub fn tender_tokens(
+        ctx: Context<TenderTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::tender_tokens::handler(ctx, amount)
+    }
+
+    /// Withdraw tendered tokens before the offer settles
+    pub fn withdraw_tender(
+        ctx: Context<WithdrawTender>,
+    ) -> Result<()> {
+        instructions::withdraw_tender::handler(ctx)
+    }
+
+    /// Settle an expired tender offer, acquiring the tendered tokens if the threshold is met
+    pub fn settle_tender_offer(
+        ctx: Context<SettleTenderOffer>,
+    ) -> Result<()> {
+        instructions::settle_tender_offer::handler(ctx)
+    }
+
+    /// Claim payment for tokens tendered into a settled offer
+    pub fn claim_tender_payment(
+        ctx: Context<ClaimTenderPayment>,
+    ) -> Result<()> {
+        instructions::claim_tender_payment::handler(ctx)
+    }
+
+    /// Surrender remaining tokens to a settled offer at the offer price (the
+    /// only on-chain exit once trading is frozen)
+    pub fn surrender_tokens(
+        ctx: Context<SurrenderTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::surrender_tokens::handler(ctx, amount)
+    }
+
+    /// Reclaim escrowed funds from a failed tender offer, closing it once tenders are withdrawn
+    pub fn reclaim_tender_funds(
+        ctx: Context<ReclaimTenderFunds>,
+    ) -> Result<()> {
+        instructions::reclaim_tender_funds::handler(ctx)
+    }
+
+    /// Cancel a reservation before it starts and refund the driver
+    pub fn cancel_reservation(
+        ctx: Context<CancelReservation>,
+    ) -> Result<()> {
+        instructions::cancel_reservation::handler(ctx)
+    }
+
+    /// Mark a reservation completed and free its calendar slot
+    pub fn complete_reservation(
+        ctx: Context<CompleteReservation>,
+    ) -> Result<()> {
+        instructions::complete_reservation::handler(ctx)
+    }
+
+    /// Refund a charging session that never received a signed meter reading
+    pub fn expire_charging_session(
+        ctx: Context<ExpireChargingSession>,
+    ) -> Result<()> {
+        instructions::expire_charging_session::handler(ctx)
+    }
+
+    /// Seed an index with its first in-band deposit and lock the dead shares
+    pub fn seed_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeedIndex<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::seed_index::handler(ctx, amounts)
+    }
+
+    /// Lock in a decommissioned asset's sale proceeds and open final redemption
+    pub fn finalize_proceeds(
+        ctx: Context<FinalizeProceeds>,
+    ) -> Result<()> {
+        instructions::finalize_proceeds::handler(ctx)
+    }
+
+    /// Stop selling and renewing passes from a subscription offer
+    pub fn deactivate_subscription_offer(
+        ctx: Context<DeactivateSubscriptionOffer>,
+    ) -> Result<()> {
+        instructions::deactivate_subscription_offer::handler(ctx)
+    }
+
+    /// Add a vault collecting the index positions' revenue in a payout mint
+    pub fn add_index_revenue_vault(
+        ctx: Context<AddIndexRevenueVault>,
+    ) -> Result<()> {
+        instructions::add_index_revenue_vault::handler(ctx)
+    }
+
+    /// Collect a constituent's revenue distribution into the index basket
+    pub fn collect_index_revenue(
+        ctx: Context<CollectIndexRevenue>,
+        token_balance: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::collect_index_revenue::handler(ctx, token_balance, proof)
+    }
 }
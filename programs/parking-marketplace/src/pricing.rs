@@ -0,0 +1,239 @@
+//! Usage price quoting shared by the program and off-chain clients.
+//!
+//! Everything here is pure arithmetic over [`PricingRates`], so a client
+//! linking this crate with the `no-entrypoint` feature computes exactly the
+//! price the program will charge.
+
+use crate::state::{PricingRates, PricingType};
+
+pub const SECONDS_PER_HOUR: i64 = 60 * 60;
+pub const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+pub const SECONDS_PER_MONTH: i64 = 30 * SECONDS_PER_DAY;
+
+/// Longest interval that can be quoted (31 days)
+pub const MAX_QUOTE_SECONDS: i64 = 31 * SECONDS_PER_DAY;
+
+/// Basis point denominator for multipliers (10000 = 1.0x)
+pub const MULTIPLIER_BASE_BPS: u128 = 10000;
+
+/// Quote the price of using a spot from `start_time` to `end_time`
+/// (unix timestamps, 6 decimals). Returns `None` for an invalid interval
+/// or on overflow.
+pub fn quote(rates: &PricingRates, start_time: i64, end_time: i64) -> Option<u64> {
+    let duration = end_time.checked_sub(start_time)?;
+    if duration <= 0 || duration > MAX_QUOTE_SECONDS {
+        return None;
+    }
+
+    let hourly = hourly_price(rates, start_time, end_time)?;
+
+    match rates.pricing_type {
+        PricingType::Hourly => Some(hourly),
+        PricingType::Daily => {
+            Some(period_price(duration, SECONDS_PER_DAY, rates.price_per_day)?.unwrap_or(hourly))
+        }
+        PricingType::Weekly => {
+            Some(period_price(duration, SECONDS_PER_WEEK, rates.price_per_week)?.unwrap_or(hourly))
+        }
+        PricingType::Monthly => {
+            Some(period_price(duration, SECONDS_PER_MONTH, rates.price_per_month)?.unwrap_or(hourly))
+        }
+        PricingType::BestRate => {
+            // Longer tariffs only apply once the stay covers at least one full period
+            let mut best = hourly;
+            for (period, rate) in [
+                (SECONDS_PER_DAY, rates.price_per_day),
+                (SECONDS_PER_WEEK, rates.price_per_week),
+                (SECONDS_PER_MONTH, rates.price_per_month),
+            ] {
+                if duration < period {
+                    continue;
+                }
+                if let Some(price) = period_price(duration, period, rate)? {
+                    best = best.min(price);
+                }
+            }
+            Some(best)
+        }
+    }
+}
+
+/// Price per started hour, applying weekday and peak-hour multipliers
+pub fn hourly_price(rates: &PricingRates, start_time: i64, end_time: i64) -> Option<u64> {
+    let mut total: u128 = 0;
+    let mut slot_start = start_time;
+
+    while slot_start < end_time {
+        let local_time = slot_start.checked_add(rates.utc_offset_seconds as i64)?;
+        let multiplier = weekday_multiplier(rates, local_time) * peak_multiplier(rates, local_time);
+        let slot_price = (rates.price_per_hour as u128)
+            .checked_mul(multiplier)?
+            / (MULTIPLIER_BASE_BPS * MULTIPLIER_BASE_BPS);
+
+        total = total.checked_add(slot_price)?;
+        slot_start = slot_start.checked_add(SECONDS_PER_HOUR)?;
+    }
+
+    u64::try_from(total).ok()
+}
+
+/// Price for whole started periods, or `None` inside if the tariff is not offered
+fn period_price(duration: i64, period: i64, rate: u64) -> Option<Option<u64>> {
+    if rate == 0 {
+        return Some(None);
+    }
+    let periods = (duration as u64).checked_add(period as u64 - 1)? / period as u64;
+    Some(Some(periods.checked_mul(rate)?))
+}
+
+/// Weekday multiplier for a local timestamp (index 0 = Sunday)
+fn weekday_multiplier(rates: &PricingRates, local_time: i64) -> u128 {
+    // 1970-01-01 was a Thursday
+    let weekday = (local_time.div_euclid(SECONDS_PER_DAY) + 4).rem_euclid(7) as usize;
+    rates.weekday_multipliers_bps[weekday] as u128
+}
+
+/// Peak-hour multiplier for a local timestamp (window may wrap past midnight)
+fn peak_multiplier(rates: &PricingRates, local_time: i64) -> u128 {
+    let hour = (local_time.rem_euclid(SECONDS_PER_DAY) / SECONDS_PER_HOUR) as u8;
+    let in_peak = if rates.peak_start_hour <= rates.peak_end_hour {
+        hour >= rates.peak_start_hour && hour < rates.peak_end_hour
+    } else {
+        hour >= rates.peak_start_hour || hour < rates.peak_end_hour
+    };
+
+    if in_peak {
+        rates.peak_multiplier_bps as u128
+    } else {
+        MULTIPLIER_BASE_BPS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_PRICE: u64 = 2_000_000;
+
+    /// 1970-01-05 00:00 UTC, a Monday
+    const MONDAY: i64 = 4 * SECONDS_PER_DAY;
+
+    fn flat_rates(pricing_type: PricingType) -> PricingRates {
+        PricingRates {
+            pricing_type,
+            price_per_hour: HOUR_PRICE,
+            price_per_day: 0,
+            price_per_week: 0,
+            price_per_month: 0,
+            utc_offset_seconds: 0,
+            peak_start_hour: 0,
+            peak_end_hour: 0,
+            peak_multiplier_bps: 10000,
+            weekday_multipliers_bps: [10000; 7],
+        }
+    }
+
+    #[test]
+    fn rejects_empty_inverted_and_overlong_windows() {
+        let rates = flat_rates(PricingType::Hourly);
+        assert_eq!(quote(&rates, MONDAY, MONDAY), None);
+        assert_eq!(quote(&rates, MONDAY, MONDAY - 1), None);
+        assert_eq!(quote(&rates, MONDAY, MONDAY + MAX_QUOTE_SECONDS + 1), None);
+        assert!(quote(&rates, MONDAY, MONDAY + MAX_QUOTE_SECONDS).is_some());
+        assert_eq!(quote(&rates, i64::MIN, i64::MAX), None);
+    }
+
+    #[test]
+    fn charges_per_started_hour() {
+        let rates = flat_rates(PricingType::Hourly);
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 1), Some(HOUR_PRICE));
+        assert_eq!(quote(&rates, MONDAY, MONDAY + SECONDS_PER_HOUR), Some(HOUR_PRICE));
+        assert_eq!(quote(&rates, MONDAY, MONDAY + SECONDS_PER_HOUR + 1), Some(2 * HOUR_PRICE));
+    }
+
+    #[test]
+    fn peak_multiplier_follows_slot_start_hour() {
+        let mut rates = flat_rates(PricingType::Hourly);
+        rates.peak_start_hour = 8;
+        rates.peak_end_hour = 10;
+        rates.peak_multiplier_bps = 20000;
+        let at = |hour: i64| MONDAY + hour * SECONDS_PER_HOUR;
+
+        // 07:00-08:00 ends exactly where peak begins
+        assert_eq!(quote(&rates, at(7), at(8)), Some(HOUR_PRICE));
+        // 07:30-08:30 is a single slot starting off-peak
+        assert_eq!(quote(&rates, at(7) + 1800, at(8) + 1800), Some(HOUR_PRICE));
+        // 08:00-10:00 is two peak slots; 10:00 itself is off-peak again
+        assert_eq!(quote(&rates, at(8), at(10)), Some(4 * HOUR_PRICE));
+        assert_eq!(quote(&rates, at(10), at(11)), Some(HOUR_PRICE));
+        // A slot starting one second before the end of peak is still peak
+        assert_eq!(quote(&rates, at(10) - 1, at(11) - 1), Some(2 * HOUR_PRICE));
+    }
+
+    #[test]
+    fn peak_window_may_wrap_midnight() {
+        let mut rates = flat_rates(PricingType::Hourly);
+        rates.peak_start_hour = 22;
+        rates.peak_end_hour = 2;
+        rates.peak_multiplier_bps = 30000;
+        let at = |hour: i64| MONDAY + hour * SECONDS_PER_HOUR;
+
+        // 21:00-03:00: 21 off, 22 23 00 01 peak, 02 off
+        assert_eq!(quote(&rates, at(21), at(27)), Some(2 * HOUR_PRICE + 4 * 3 * HOUR_PRICE));
+    }
+
+    #[test]
+    fn weekday_and_utc_offset_pick_the_local_day() {
+        let mut rates = flat_rates(PricingType::Hourly);
+        rates.weekday_multipliers_bps[0] = 15000; // Sunday
+
+        let sunday_noon = MONDAY - SECONDS_PER_DAY + 12 * SECONDS_PER_HOUR;
+        assert_eq!(quote(&rates, sunday_noon, sunday_noon + 1), Some(3 * HOUR_PRICE / 2));
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 1), Some(HOUR_PRICE));
+
+        // 00:30 UTC Monday is still Sunday evening at UTC-1
+        rates.utc_offset_seconds = -3600;
+        assert_eq!(quote(&rates, MONDAY + 1800, MONDAY + 1801), Some(3 * HOUR_PRICE / 2));
+    }
+
+    #[test]
+    fn daily_and_weekly_tariffs_charge_started_periods() {
+        let mut rates = flat_rates(PricingType::Daily);
+        rates.price_per_day = 30_000_000;
+        assert_eq!(quote(&rates, MONDAY, MONDAY + SECONDS_PER_DAY), Some(30_000_000));
+        assert_eq!(quote(&rates, MONDAY, MONDAY + SECONDS_PER_DAY + 1), Some(60_000_000));
+
+        rates.pricing_type = PricingType::Weekly;
+        rates.price_per_week = 150_000_000;
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 8 * SECONDS_PER_DAY), Some(300_000_000));
+
+        // A tariff that is not offered falls back to hourly pricing
+        rates.pricing_type = PricingType::Monthly;
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 3 * SECONDS_PER_HOUR), Some(3 * HOUR_PRICE));
+    }
+
+    #[test]
+    fn best_rate_only_uses_periods_the_stay_covers() {
+        let mut rates = flat_rates(PricingType::BestRate);
+        rates.price_per_day = 10_000_000;
+
+        // 23 hours is cheaper by the day, but a day rate needs a full day
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 23 * SECONDS_PER_HOUR), Some(23 * HOUR_PRICE));
+        // 25 hours: two started days beat 25 hours
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 25 * SECONDS_PER_HOUR), Some(20_000_000));
+    }
+
+    #[test]
+    fn overflow_returns_none() {
+        let mut rates = flat_rates(PricingType::Hourly);
+        rates.price_per_hour = u64::MAX;
+        rates.peak_multiplier_bps = PricingRates::MAX_MULTIPLIER_BPS;
+        rates.peak_end_hour = 23;
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 2 * SECONDS_PER_HOUR), None);
+
+        let mut rates = flat_rates(PricingType::Daily);
+        rates.price_per_day = u64::MAX;
+        assert_eq!(quote(&rates, MONDAY, MONDAY + 2 * SECONDS_PER_DAY), None);
+    }
+}
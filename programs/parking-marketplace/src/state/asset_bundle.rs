@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct AssetBundle {
+    /// Parking asset (ParkingLotBundle type) issuing the bundle tokens
+    pub bundle_asset: Pubkey,
+
+    /// Constituent SingleSpot assets held by the bundle
+    pub components: Vec<BundleComponent>,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AssetBundle {
+    pub const MAX_COMPONENTS: usize = 8;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // bundle_asset
+        (4 + (BundleComponent::LEN * Self::MAX_COMPONENTS)) + // components
+        8 + // created_at
+        1; // bump
+
+    /// Find a component by its asset account
+    pub fn find_component(&self, asset_account: &Pubkey) -> Option<&BundleComponent> {
+        self.components
+            .iter()
+            .find(|component| component.asset_account == *asset_account)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BundleComponent {
+    /// Constituent parking asset
+    pub asset_account: Pubkey,
+
+    /// Constituent asset token mint
+    pub asset_token_mint: Pubkey,
+
+    /// Constituent tokens deposited per bundle token
+    pub units_per_bundle: u64,
+
+    /// Vault holding the constituent tokens
+    pub vault: Pubkey,
+}
+
+impl BundleComponent {
+    pub const LEN: usize = 32 + 32 + 8 + 32;
+}
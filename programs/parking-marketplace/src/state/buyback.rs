@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Buyback {
+    /// Parking asset whose tokens are being bought back
+    pub asset_account: Pubkey,
+
+    /// Payment token mint (USDC, EUROC, etc.)
+    pub payment_mint: Pubkey,
+
+    /// Published price per token (6 decimals)
+    pub price_per_token_usdc: u64,
+
+    /// Maximum tokens the operator will buy back
+    pub max_tokens: u64,
+
+    /// Tokens redeemed and burned so far
+    pub tokens_redeemed: u64,
+
+    /// Timestamp after which holders can no longer redeem
+    pub expires_at: i64,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Buyback {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // payment_mint
+        8 + // price_per_token_usdc
+        8 + // max_tokens
+        8 + // tokens_redeemed
+        8 + // expires_at
+        8 + // created_at
+        1; // bump
+
+    /// Tokens still available to redeem
+    pub fn remaining_tokens(&self) -> u64 {
+        self.max_tokens.saturating_sub(self.tokens_redeemed)
+    }
+
+    /// Check if holders can still redeem
+    pub fn is_open(&self, current_time: i64) -> bool {
+        current_time < self.expires_at && self.remaining_tokens() > 0
+    }
+}
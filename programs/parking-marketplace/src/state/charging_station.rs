@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionStatus;
+
+#[account]
+#[derive(Default)]
+pub struct ChargingStation {
+    /// Parking asset (ChargingStation type) that owns the station revenue
+    pub asset_account: Pubkey,
+
+    /// Reference to charging_stations table in database
+    pub station_id: u64,
+
+    /// Charger type
+    pub charger_type: ChargerType,
+
+    /// Number of connectors on the station
+    pub total_connectors: u8,
+
+    /// Bitmask of connectors with an active session
+    pub connectors_in_use: u32,
+
+    /// Maximum charging power in kW
+    pub max_power_kw: u32,
+
+    /// Energy, time and session fees
+    pub rates: ChargingRates,
+
+    /// Registered charger devices that sign meter readings
+    pub charger_devices: Vec<ChargerDevice>,
+
+    /// Timestamp when station was registered
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ChargingStation {
+    pub const MAX_CONNECTORS: u8 = 32;
+    pub const MAX_CHARGER_DEVICES: usize = 8;
+
+    /// Sessions without a signed reading this long after start can be
+    /// expired and refunded in full (24 hours)
+    pub const SESSION_TIMEOUT_SECONDS: i64 = 24 * 60 * 60;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        8 + // station_id
+        1 + // charger_type
+        1 + // total_connectors
+        4 + // connectors_in_use
+        4 + // max_power_kw
+        ChargingRates::LEN + // rates
+        (4 + (ChargerDevice::LEN * Self::MAX_CHARGER_DEVICES)) + // charger_devices
+        8 + // created_at
+        1; // bump
+
+    /// Check if a connector exists and is free
+    pub fn is_connector_available(&self, connector_id: u8) -> bool {
+        connector_id < self.total_connectors && self.connectors_in_use & (1 << connector_id) == 0
+    }
+
+    /// Mark a connector as in use or free
+    pub fn set_connector_in_use(&mut self, connector_id: u8, in_use: bool) {
+        if in_use {
+            self.connectors_in_use |= 1 << connector_id;
+        } else {
+            self.connectors_in_use &= !(1 << connector_id);
+        }
+    }
+
+    /// Find a registered charger device by key
+    pub fn find_device_mut(&mut self, device_key: &Pubkey) -> Option<&mut ChargerDevice> {
+        self.charger_devices
+            .iter_mut()
+            .find(|device| device.device_key == *device_key)
+    }
+
+    /// Check that delivered energy is physically possible for the session length
+    pub fn is_plausible_energy(&self, energy_wh: u64, duration_seconds: i64) -> bool {
+        if duration_seconds <= 0 {
+            return energy_wh == 0;
+        }
+        // Wh * 3600 <= kW * 1000 * seconds
+        energy_wh as u128 * 3600 <= self.max_power_kw as u128 * 1000 * duration_seconds as u128
+    }
+
+    /// Session cost: energy + started minutes + flat session fee
+    pub fn session_cost(&self, energy_wh: u64, duration_seconds: i64) -> Option<u64> {
+        let rates = &self.rates;
+        let energy_cost = (energy_wh as u128)
+            .checked_mul(rates.price_per_kwh_usdc as u128)?
+            / 1000;
+        let minutes = (duration_seconds.max(0) as u64).checked_add(59)? / 60;
+        let time_cost = (minutes as u128).checked_mul(rates.price_per_minute_usdc as u128)?;
+
+        let total = energy_cost
+            .checked_add(time_cost)?
+            .checked_add(rates.price_per_session_usdc as u128)?;
+        u64::try_from(total).ok()
+    }
+}
+
+/// Charging tariffs (6 decimals)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChargingRates {
+    /// Energy price per kWh
+    pub price_per_kwh_usdc: u64,
+
+    /// Time fee per started minute
+    pub price_per_minute_usdc: u64,
+
+    /// Flat fee per session
+    pub price_per_session_usdc: u64,
+}
+
+impl ChargingRates {
+    pub const LEN: usize = 8 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChargerDevice {
+    /// Ed25519 key the charger signs meter readings with
+    pub device_key: Pubkey,
+
+    /// Connector the device meters
+    pub connector_id: u8,
+
+    /// Timestamp of the most recent accepted reading
+    pub last_reading_at: i64,
+}
+
+impl ChargerDevice {
+    pub const LEN: usize = 32 + 1 + 8;
+}
+
+/// Meter reading signed by a charger device at the end of a session.
+/// The Borsh serialization of this struct is the signed message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct MeterReading {
+    /// Charging session the reading belongs to
+    pub session_account: Pubkey,
+
+    /// Energy delivered during the session in Wh
+    pub energy_delivered_wh: u64,
+
+    /// Reading timestamp
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChargerType {
+    Ac,        // AC charger
+    DcFast,    // DC fast charger
+    UltraFast, // Ultra-fast DC charger
+}
+
+impl Default for ChargerType {
+    fn default() -> Self {
+        ChargerType::Ac
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct ChargingSession {
+    /// Charging station used
+    pub station_account: Pubkey,
+
+    /// Driver who started the session
+    pub driver: Pubkey,
+
+    /// Connector the vehicle is plugged into
+    pub connector_id: u8,
+
+    /// Token mint escrowed and paid in
+    pub payment_mint: Pubkey,
+
+    /// Maximum amount escrowed by the driver (6 decimals)
+    pub max_amount_usdc: u64,
+
+    /// Amount charged at settlement (6 decimals)
+    pub amount_charged_usdc: u64,
+
+    /// Energy delivered in Wh
+    pub energy_delivered_wh: u64,
+
+    /// Charger device that signed the final meter reading
+    pub metered_by: Pubkey,
+
+    /// Session start timestamp
+    pub started_at: i64,
+
+    /// Session end timestamp (0 while active)
+    pub ended_at: i64,
+
+    /// Session status
+    pub status: SessionStatus,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ChargingSession {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // station_account
+        32 + // driver
+        1 + // connector_id
+        32 + // payment_mint
+        8 + // max_amount_usdc
+        8 + // amount_charged_usdc
+        8 + // energy_delivered_wh
+        32 + // metered_by
+        8 + // started_at
+        8 + // ended_at
+        1 + // status
+        1; // bump
+
+    /// Check if the session can be expired for lack of a meter reading
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.started_at.saturating_add(ChargingStation::SESSION_TIMEOUT_SECONDS)
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::SweepDestination;
+
+#[account]
+#[derive(Default)]
+pub struct Decommission {
+    /// Parking asset being wound down
+    pub asset_account: Pubkey,
+
+    /// Token mint the proceeds are paid in
+    pub payment_mint: Pubkey,
+
+    /// Total sale proceeds deposited (6 decimals)
+    pub proceeds_usdc: u64,
+
+    /// Tokens burned through final redemption
+    pub tokens_redeemed: u64,
+
+    /// Proceeds paid out to holders (6 decimals)
+    pub amount_paid_usdc: u64,
+
+    /// Sale proceeds are locked in and redemptions are open
+    pub proceeds_finalized: bool,
+
+    /// Length of the redemption window, counted from finalization
+    pub redemption_window_seconds: i64,
+
+    /// Timestamp after which holders can no longer redeem (0 until finalized)
+    pub redemption_deadline: i64,
+
+    /// Where unclaimed proceeds go once the window closes
+    pub unclaimed_destination: SweepDestination,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Decommission {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // payment_mint
+        8 + // proceeds_usdc
+        8 + // tokens_redeemed
+        8 + // amount_paid_usdc
+        1 + // proceeds_finalized
+        8 + // redemption_window_seconds
+        8 + // redemption_deadline
+        1 + // unclaimed_destination
+        8 + // created_at
+        1; // bump
+
+    /// Shortest redemption window an operator may configure (30 days)
+    pub const MIN_REDEMPTION_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Check if holders can redeem
+    pub fn is_redemption_open(&self, current_time: i64) -> bool {
+        self.proceeds_finalized && current_time < self.redemption_deadline
+    }
+
+    /// Pro rata share of the remaining proceeds for `amount` of the
+    /// `outstanding` tokens
+    pub fn redemption_amount(vault_balance: u64, amount: u64, outstanding: u64) -> u64 {
+        if outstanding == 0 {
+            return 0;
+        }
+        ((vault_balance as u128 * amount as u128) / outstanding as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redemption_amount_is_pro_rata_of_the_remaining_proceeds() {
+        assert_eq!(Decommission::redemption_amount(1_000_000, 25, 100), 250_000);
+        assert_eq!(Decommission::redemption_amount(1_000_000, 100, 100), 1_000_000);
+        assert_eq!(Decommission::redemption_amount(1_000_000, 1, 0), 0);
+
+        // Rounding down never lets later holders receive less than earlier ones
+        let first = Decommission::redemption_amount(10, 1, 3);
+        let second = Decommission::redemption_amount(10 - first, 1, 2);
+        let last = Decommission::redemption_amount(10 - first - second, 1, 1);
+        assert_eq!((first, second, last), (3, 3, 4));
+    }
+
+    #[test]
+    fn redemption_amount_handles_large_balances() {
+        assert_eq!(
+            Decommission::redemption_amount(u64::MAX, u64::MAX, u64::MAX),
+            u64::MAX
+        );
+        assert_eq!(Decommission::redemption_amount(u64::MAX, 1, u64::MAX), 1);
+    }
+}
@@ -0,0 +1,273 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::error::MarketplaceError;
+use crate::state::ParkingAsset;
+
+/// Index of parking assets. Index tokens are minted and redeemed against a
+/// pro-rata basket of the vault balances rather than at a NAV computed from
+/// estimated asset values, so an operator-set valuation can never be used to
+/// mint cheap or redeem rich. Estimated values only steer rebalancing.
+#[account]
+#[derive(Default)]
+pub struct IndexFund {
+    /// Manager allowed to configure and rebalance the index
+    pub manager: Pubkey,
+
+    /// SPL token mint for the index tokens
+    pub index_mint: Pubkey,
+
+    /// Weighted positions held by the index
+    pub positions: Vec<IndexPosition>,
+
+    /// Token accounts collecting the positions' revenue, one per payout mint
+    pub revenue_vaults: Vec<Pubkey>,
+
+    /// Allowed deviation from target weight before rebalancing (basis points)
+    pub band_bps: u16,
+
+    /// Minimum time between rebalances
+    pub min_rebalance_interval: i64,
+
+    /// Last rebalance timestamp
+    pub last_rebalanced_at: i64,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl IndexFund {
+    pub const MAX_POSITIONS: usize = 8;
+    pub const MAX_REVENUE_VAULTS: usize = 4;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // manager
+        32 + // index_mint
+        (4 + (IndexPosition::LEN * Self::MAX_POSITIONS)) + // positions
+        (4 + (32 * Self::MAX_REVENUE_VAULTS)) + // revenue_vaults
+        2 + // band_bps
+        8 + // min_rebalance_interval
+        8 + // last_rebalanced_at
+        8 + // created_at
+        1; // bump
+
+    /// Index tokens use six decimals so that one token starts at one USDC
+    pub const INDEX_DECIMALS: u8 = 6;
+
+    /// Smallest value the first deposit must bring in (100 USDC)
+    pub const MIN_SEED_VALUE_USDC: u64 = 100_000_000;
+
+    /// Index tokens locked forever at seeding so supply never returns to zero
+    pub const DEAD_SHARES: u64 = 1_000_000;
+
+    /// Vaults making up one index token's basket: every position vault, then
+    /// every revenue vault
+    pub fn basket_vaults(&self) -> impl Iterator<Item = Pubkey> + '_ {
+        self.positions
+            .iter()
+            .map(|position| position.vault)
+            .chain(self.revenue_vaults.iter().copied())
+    }
+
+    /// Number of vaults in the basket
+    pub fn basket_len(&self) -> usize {
+        self.positions.len() + self.revenue_vaults.len()
+    }
+
+    /// Check if the index holds a position in an asset
+    pub fn holds_asset(&self, asset_account: &Pubkey) -> bool {
+        self.positions
+            .iter()
+            .any(|position| position.asset_account == *asset_account)
+    }
+
+    /// Check that target weights add up to 100%
+    pub fn is_fully_weighted(&self) -> bool {
+        self.total_weight_bps() == 10000
+    }
+
+    /// Sum of the positions' target weights
+    pub fn total_weight_bps(&self) -> u32 {
+        self.positions
+            .iter()
+            .map(|position| position.target_weight_bps as u32)
+            .sum()
+    }
+
+    /// Check whether a position's value lies within its weight band
+    pub fn is_within_band(&self, position: &IndexPosition, value: u64, nav: u64) -> bool {
+        let weight = weight_bps(value, nav);
+        let target = position.target_weight_bps as u64;
+        let band = self.band_bps as u64;
+        weight + band >= target && weight <= target + band
+    }
+
+    /// Value each position at its asset's estimated value.
+    /// `accounts` holds [asset account, vault] for every position, in order.
+    pub fn position_values<'info>(
+        &self,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<u64>> {
+        require!(
+            accounts.len() == self.positions.len() * 2,
+            MarketplaceError::InvalidIndexAccounts
+        );
+
+        let mut values = Vec::with_capacity(self.positions.len());
+        for (position, pair) in self.positions.iter().zip(accounts.chunks(2)) {
+            require!(
+                pair[0].key() == position.asset_account && pair[1].key() == position.vault,
+                MarketplaceError::InvalidIndexAccounts
+            );
+            let asset = Account::<ParkingAsset>::try_from(&pair[0])?;
+            let vault = Account::<TokenAccount>::try_from(&pair[1])?;
+            let value = asset
+                .token_value(vault.amount)
+                .ok_or(MarketplaceError::ArithmeticOverflow)?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Asset tokens a depositor must add to a position vault to mint
+    /// `index_amount` against `supply`: a pro-rata share, rounded up
+    pub fn basket_deposit(vault_balance: u64, index_amount: u64, supply: u64) -> Option<u64> {
+        if supply == 0 {
+            return None;
+        }
+        let amount = (vault_balance as u128)
+            .checked_mul(index_amount as u128)?
+            .checked_add(supply as u128 - 1)?
+            / supply as u128;
+        u64::try_from(amount).ok()
+    }
+
+    /// Asset tokens paid out of a position vault for redeeming
+    /// `index_amount` against `supply`: a pro-rata share, rounded down
+    pub fn basket_withdrawal(vault_balance: u64, index_amount: u64, supply: u64) -> Option<u64> {
+        if supply == 0 || index_amount > supply {
+            return None;
+        }
+        let amount = (vault_balance as u128).checked_mul(index_amount as u128)? / supply as u128;
+        u64::try_from(amount).ok()
+    }
+
+    /// Split the index tokens for a seed deposit worth `nav` between the
+    /// depositor and the permanently locked dead shares
+    pub fn seed_allocation(nav: u64) -> Option<(u64, u64)> {
+        if nav < Self::MIN_SEED_VALUE_USDC {
+            return None;
+        }
+        Some((nav - Self::DEAD_SHARES, Self::DEAD_SHARES))
+    }
+}
+
+/// Weight of a value in the total NAV (basis points)
+pub fn weight_bps(value: u64, nav: u64) -> u64 {
+    if nav == 0 {
+        return 0;
+    }
+    ((value as u128 * 10000) / nav as u128) as u64
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IndexPosition {
+    /// Parking asset held by the index
+    pub asset_account: Pubkey,
+
+    /// Asset token mint
+    pub asset_token_mint: Pubkey,
+
+    /// Target weight in the index (basis points)
+    pub target_weight_bps: u16,
+
+    /// Vault holding the asset tokens
+    pub vault: Pubkey,
+}
+
+impl IndexPosition {
+    pub const LEN: usize = 32 + 32 + 2 + 32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basket_deposit_rounds_up_and_withdrawal_rounds_down() {
+        // 1000 asset tokens backing 300 index tokens
+        assert_eq!(IndexFund::basket_deposit(1000, 100, 300), Some(334));
+        assert_eq!(IndexFund::basket_withdrawal(1000, 100, 300), Some(333));
+        assert_eq!(IndexFund::basket_deposit(900, 100, 300), Some(300));
+        assert_eq!(IndexFund::basket_withdrawal(900, 100, 300), Some(300));
+
+        // A mint immediately redeemed never returns more than was deposited
+        let deposit = IndexFund::basket_deposit(7, 1, 3).unwrap();
+        let withdrawal = IndexFund::basket_withdrawal(7 + deposit, 1, 4).unwrap();
+        assert!(withdrawal <= deposit);
+    }
+
+    #[test]
+    fn basket_rejects_empty_supply_and_over_redemption() {
+        assert_eq!(IndexFund::basket_deposit(1000, 1, 0), None);
+        assert_eq!(IndexFund::basket_withdrawal(1000, 1, 0), None);
+        assert_eq!(IndexFund::basket_withdrawal(1000, 301, 300), None);
+        assert_eq!(IndexFund::basket_withdrawal(1000, 300, 300), Some(1000));
+        assert_eq!(IndexFund::basket_deposit(0, 100, 300), Some(0));
+    }
+
+    #[test]
+    fn basket_handles_large_balances() {
+        assert_eq!(IndexFund::basket_deposit(u64::MAX, u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(IndexFund::basket_withdrawal(u64::MAX, 1, u64::MAX), Some(1));
+        assert_eq!(IndexFund::basket_deposit(u64::MAX, u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn seed_requires_minimum_value_and_locks_dead_shares() {
+        assert_eq!(IndexFund::seed_allocation(IndexFund::MIN_SEED_VALUE_USDC - 1), None);
+        assert_eq!(
+            IndexFund::seed_allocation(IndexFund::MIN_SEED_VALUE_USDC),
+            Some((
+                IndexFund::MIN_SEED_VALUE_USDC - IndexFund::DEAD_SHARES,
+                IndexFund::DEAD_SHARES
+            ))
+        );
+    }
+
+    #[test]
+    fn weight_bands() {
+        let fund = IndexFund {
+            band_bps: 500,
+            ..Default::default()
+        };
+        let position = IndexPosition {
+            target_weight_bps: 4000,
+            ..Default::default()
+        };
+        assert_eq!(weight_bps(350, 1000), 3500);
+        assert_eq!(weight_bps(1, 0), 0);
+        assert!(fund.is_within_band(&position, 350, 1000));
+        assert!(fund.is_within_band(&position, 450, 1000));
+        assert!(!fund.is_within_band(&position, 349, 1000));
+        assert!(!fund.is_within_band(&position, 451, 1000));
+    }
+
+    #[test]
+    fn basket_lists_positions_before_revenue_vaults() {
+        let position = |vault| IndexPosition {
+            vault,
+            ..Default::default()
+        };
+        let (a, b, revenue) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let fund = IndexFund {
+            positions: vec![position(a), position(b)],
+            revenue_vaults: vec![revenue],
+            ..Default::default()
+        };
+        assert_eq!(fund.basket_len(), 3);
+        assert_eq!(fund.basket_vaults().collect::<Vec<_>>(), vec![a, b, revenue]);
+    }
+}
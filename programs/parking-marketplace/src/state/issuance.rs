@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::HolderSnapshot;
+
+#[account]
+#[derive(Default)]
+pub struct Issuance {
+    /// Parking asset issuing additional tokens
+    pub asset_account: Pubkey,
+
+    /// Tokens offered in this issuance
+    pub amount: u64,
+
+    /// Price per token (6 decimals)
+    pub price_per_token_usdc: u64,
+
+    /// Payment token mint (USDC, EUROC, etc.)
+    pub payment_mint: Pubkey,
+
+    /// Supply committed by the holder snapshot (circulating supply if the
+    /// issuance has no pre-emption window)
+    pub snapshot_supply: u64,
+
+    /// Merkle root of holder balances when the issuance was announced;
+    /// pre-emption rights are sized from it
+    pub holder_snapshot_root: [u8; 32],
+
+    /// Tokens sold so far
+    pub tokens_sold: u64,
+
+    /// End of the holder pre-emption window (equal to created_at if none)
+    pub preemption_ends_at: i64,
+
+    /// End of the public sale
+    pub sale_ends_at: i64,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Issuance {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        8 + // amount
+        8 + // price_per_token_usdc
+        32 + // payment_mint
+        8 + // snapshot_supply
+        32 + // holder_snapshot_root
+        8 + // tokens_sold
+        8 + // preemption_ends_at
+        8 + // sale_ends_at
+        8 + // created_at
+        1; // bump
+
+    /// Tokens not yet sold
+    pub fn remaining_tokens(&self) -> u64 {
+        self.amount.saturating_sub(self.tokens_sold)
+    }
+
+    /// Check if existing holders have exclusive access
+    pub fn is_preemption_open(&self, current_time: i64) -> bool {
+        current_time < self.preemption_ends_at
+    }
+
+    /// Check if anyone may subscribe
+    pub fn is_public_sale_open(&self, current_time: i64) -> bool {
+        current_time >= self.preemption_ends_at && current_time < self.sale_ends_at
+    }
+
+    /// Pro rata share of the issuance for a holder's token balance
+    pub fn preemption_allocation(&self, holder_balance: u64) -> u64 {
+        if self.snapshot_supply == 0 {
+            return 0;
+        }
+        ((holder_balance as u128 * self.amount as u128) / self.snapshot_supply as u128) as u64
+    }
+}
+
+/// Holder snapshot for a pre-emption window, signed off-chain by the asset's
+/// revenue oracle. The Borsh serialization of this struct is the signed message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PreemptionSnapshotReport {
+    /// Parking asset issuing the tokens
+    pub asset_account: Pubkey,
+
+    /// Asset's revenue report nonce; each signed report is usable once
+    pub nonce: u64,
+
+    /// Holder balances when the issuance is announced
+    pub holder_snapshot: HolderSnapshot,
+}
+
+#[account]
+#[derive(Default)]
+pub struct PreemptionAllocation {
+    /// Issuance the allocation belongs to
+    pub issuance: Pubkey,
+
+    /// Holder exercising pre-emption rights
+    pub holder: Pubkey,
+
+    /// Tokens bought during the pre-emption window
+    pub amount: u64,
+
+    /// Exercise timestamp
+    pub exercised_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PreemptionAllocation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // issuance
+        32 + // holder
+        8 + // amount
+        8 + // exercised_at
+        1; // bump
+}
@@ -12,6 +12,12 @@ pub struct MarketplaceListing {
     /// Type of listing
     pub listing_type: ListingType,
 
+    /// What is being sold (asset tokens or a reservation)
+    pub listing_kind: ListingKind,
+
+    /// Reservation being resold (default key for token listings)
+    pub reservation: Pubkey,
+
     /// Number of tokens for sale
     pub token_amount: u64,
 
@@ -50,6 +56,8 @@ impl MarketplaceListing {
         32 + // asset_account
         32 + // seller
         1 + // listing_type
+        1 + // listing_kind
+        32 + // reservation
         8 + // token_amount
         8 + // price_per_token_usdc
         8 + // total_price_usdc
@@ -85,6 +93,18 @@ impl Default for ListingType {
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ListingKind {
+    AssetTokens, // Parking asset tokens
+    Reservation, // Resale of a spot reservation
+}
+
+impl Default for ListingKind {
+    fn default() -> Self {
+        ListingKind::AssetTokens
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum ListingStatus {
     Active,
@@ -1,7 +1,39 @@
 pub mod parking_asset;
 pub mod marketplace;
 pub mod revenue_distribution;
+pub mod protocol_config;
+pub mod revenue_vault;
+pub mod reservation;
+pub mod pricing_schedule;
+pub mod parking_session;
+pub mod charging_station;
+pub mod subscription;
+pub mod asset_bundle;
+pub mod index_fund;
+pub mod buyback;
+pub mod issuance;
+pub mod operator_transfer;
+pub mod operator_multisig;
+pub mod timelocked_change;
+pub mod decommission;
+pub mod tender_offer;
 
 pub use parking_asset::*;
 pub use marketplace::*;
 pub use revenue_distribution::*;
+pub use protocol_config::*;
+pub use revenue_vault::*;
+pub use reservation::*;
+pub use pricing_schedule::*;
+pub use parking_session::*;
+pub use charging_station::*;
+pub use subscription::*;
+pub use asset_bundle::*;
+pub use index_fund::*;
+pub use buyback::*;
+pub use issuance::*;
+pub use operator_transfer::*;
+pub use operator_multisig::*;
+pub use timelocked_change::*;
+pub use decommission::*;
+pub use tender_offer::*;
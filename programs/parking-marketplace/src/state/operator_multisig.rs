@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct OperatorMultisig {
+    /// Unique key the multisig PDA is derived from
+    pub create_key: Pubkey,
+
+    /// Registered signers
+    pub signers: Vec<Pubkey>,
+
+    /// Approvals required to execute an action
+    pub threshold: u8,
+
+    /// Number of actions proposed so far (seeds the next action)
+    pub action_count: u64,
+
+    /// Bump of the multisig signer PDA used as institutional operator
+    pub signer_bump: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OperatorMultisig {
+    pub const MAX_SIGNERS: usize = 10;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // create_key
+        (4 + (32 * Self::MAX_SIGNERS)) + // signers (vec of pubkeys)
+        1 + // threshold
+        8 + // action_count
+        1 + // signer_bump
+        1; // bump
+
+    /// Check if a key is a registered signer
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    /// Check a signer set and threshold
+    pub fn is_valid_config(signers: &[Pubkey], threshold: u8) -> bool {
+        let mut unique = signers.to_vec();
+        unique.sort();
+        unique.dedup();
+
+        !signers.is_empty()
+            && signers.len() <= Self::MAX_SIGNERS
+            && unique.len() == signers.len()
+            && threshold > 0
+            && threshold as usize <= signers.len()
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct PendingAction {
+    /// Multisig the action belongs to
+    pub multisig: Pubkey,
+
+    /// Signer who proposed the action
+    pub proposer: Pubkey,
+
+    /// Action number within the multisig
+    pub index: u64,
+
+    /// Accounts of the marketplace instruction to execute
+    pub accounts: Vec<ActionAccount>,
+
+    /// Instruction data of the marketplace instruction to execute
+    pub data: Vec<u8>,
+
+    /// Signers who approved the action
+    pub approvals: Vec<Pubkey>,
+
+    /// Timestamp after which the action can no longer execute
+    pub expires_at: i64,
+
+    /// Proposal timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingAction {
+    pub const MAX_ACCOUNTS: usize = 24;
+    pub const MAX_DATA_LEN: usize = 512;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        32 + // proposer
+        8 + // index
+        (4 + (ActionAccount::LEN * Self::MAX_ACCOUNTS)) + // accounts
+        (4 + Self::MAX_DATA_LEN) + // data
+        (4 + (32 * OperatorMultisig::MAX_SIGNERS)) + // approvals (vec of pubkeys)
+        8 + // expires_at
+        8 + // created_at
+        1; // bump
+
+    /// Check if the action has expired
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ActionAccount {
+    /// Account address
+    pub pubkey: Pubkey,
+
+    /// Whether the account signs the instruction
+    pub is_signer: bool,
+
+    /// Whether the account is writable
+    pub is_writable: bool,
+}
+
+impl ActionAccount {
+    pub const LEN: usize = 32 + 1 + 1;
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct OperatorTransfer {
+    /// Parking lot whose assets change management
+    pub parking_lot_id: u64,
+
+    /// Operator handing over the lot
+    pub current_operator: Pubkey,
+
+    /// Operator taking over the lot
+    pub proposed_operator: Pubkey,
+
+    /// Number of the lot's assets held by the current operator
+    pub asset_count: u32,
+
+    /// Number of assets handed over so far
+    pub assets_transferred: u32,
+
+    /// Whether the compliance authority must approve before acceptance
+    pub requires_approval: bool,
+
+    /// Whether the compliance authority has approved
+    pub is_approved: bool,
+
+    /// Proposal timestamp
+    pub proposed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OperatorTransfer {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // parking_lot_id
+        32 + // current_operator
+        32 + // proposed_operator
+        4 + // asset_count
+        4 + // assets_transferred
+        1 + // requires_approval
+        1 + // is_approved
+        8 + // proposed_at
+        1; // bump
+
+    /// Check if the proposed operator may accept
+    pub fn is_acceptable(&self) -> bool {
+        !self.requires_approval || self.is_approved
+    }
+
+    /// Check if every asset of the lot has been handed over
+    pub fn is_complete(&self) -> bool {
+        self.assets_transferred == self.asset_count
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{OperatingCosts, PauseFlags};
 
 #[account]
 #[derive(Default)]
@@ -45,6 +46,30 @@ pub struct ParkingAsset {
     /// Timestamp when asset was tokenized
     pub created_at: i64,
 
+    /// End of the most recent revenue distribution period (0 if none)
+    pub last_distributed_period_end: i64,
+
+    /// Oracle that must sign revenue reports (default key if not required)
+    pub revenue_oracle: Pubkey,
+
+    /// Per-category operating cost caps relative to gross revenue
+    pub cost_caps: OperatingCostCaps,
+
+    /// Usage discount for large token holders (SingleSpot only)
+    pub holder_privilege: HolderPrivilege,
+
+    /// Maximum total supply follow-on issuances may reach
+    pub max_supply: u64,
+
+    /// Tokens cleared through the timelock for follow-on issuance
+    pub authorized_issuance: u64,
+
+    /// Per-asset pause flags set by the guardian
+    pub paused: PauseFlags,
+
+    /// Nonce the next signed revenue report must carry (one report per distribution)
+    pub revenue_report_nonce: u64,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -65,6 +90,14 @@ impl ParkingAsset {
         1 + // is_active
         1 + // is_tradeable
         8 + // created_at
+        8 + // last_distributed_period_end
+        32 + // revenue_oracle
+        OperatingCostCaps::LEN + // cost_caps
+        HolderPrivilege::LEN + // holder_privilege
+        8 + // max_supply
+        8 + // authorized_issuance
+        PauseFlags::LEN + // paused
+        8 + // revenue_report_nonce
         1; // bump
 
     /// Calculate annual yield percentage
@@ -75,6 +108,70 @@ impl ParkingAsset {
         // Returns yield in basis points (10000 = 100%)
         (self.annual_revenue_usdc * 10000) / self.estimated_value_usdc
     }
+
+    /// Value of a number of asset tokens at the asset's estimated value
+    pub fn token_value(&self, amount: u64) -> Option<u64> {
+        if self.total_supply == 0 {
+            return None;
+        }
+        let value = (amount as u128)
+            .checked_mul(self.estimated_value_usdc as u128)?
+            / self.total_supply as u128;
+        u64::try_from(value).ok()
+    }
+
+    /// Number of asset tokens worth a value at the asset's estimated value
+    pub fn tokens_for_value(&self, value: u64) -> Option<u64> {
+        if self.estimated_value_usdc == 0 {
+            return None;
+        }
+        let amount = (value as u128)
+            .checked_mul(self.total_supply as u128)?
+            / self.estimated_value_usdc as u128;
+        u64::try_from(amount).ok()
+    }
+
+    /// Check that minting `amount` more tokens stays within the supply cap
+    pub fn can_issue(&self, amount: u64) -> bool {
+        self.total_supply
+            .checked_add(amount)
+            .is_some_and(|supply| supply <= self.max_supply)
+    }
+
+    /// Check if revenue reports must be signed by an oracle
+    pub fn requires_revenue_attestation(&self) -> bool {
+        self.revenue_oracle != Pubkey::default()
+    }
+
+    /// Check if a token balance earns the holder discount on this spot
+    pub fn grants_holder_discount(&self, holder_balance: u64) -> bool {
+        self.asset_type == AssetType::SingleSpot
+            && self.holder_privilege.discount_bps > 0
+            && self.holder_privilege.qualifies(holder_balance, self.total_supply)
+    }
+
+    /// Price a holder pays for using this spot, given their token balance
+    pub fn holder_price(&self, price: u64, holder_balance: u64) -> u64 {
+        if self.grants_holder_discount(holder_balance) {
+            self.holder_privilege.apply(price)
+        } else {
+            price
+        }
+    }
+
+    /// Check that a new revenue period continues the distribution history.
+    /// The first period may start anywhere; later periods must start where the
+    /// previous one ended unless a gap is explicitly declared.
+    pub fn is_valid_next_period(&self, period_start: i64, declared_gap: bool) -> bool {
+        if self.last_distributed_period_end == 0 {
+            return true;
+        }
+        if declared_gap {
+            period_start > self.last_distributed_period_end
+        } else {
+            period_start == self.last_distributed_period_end
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -82,6 +179,7 @@ pub enum AssetType {
     SingleSpot,       // Individual parking spot
     RevenueShare,     // Share of parking lot revenue
     ParkingLotBundle, // Bundle of multiple spots
+    ChargingStation,  // EV charging station
 }
 
 impl Default for AssetType {
@@ -90,6 +188,106 @@ impl Default for AssetType {
     }
 }
 
+/// Discount on reservations and sessions for holders of a share of the spot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HolderPrivilege {
+    /// Minimum share of total supply held (basis points, 0 = disabled)
+    pub threshold_bps: u16,
+
+    /// Discount granted (basis points, at most MAX_DISCOUNT_BPS)
+    pub discount_bps: u16,
+}
+
+impl HolderPrivilege {
+    pub const LEN: usize = 2 + 2;
+
+    /// Largest discount an operator may grant (50%), so holders cannot
+    /// block out the calendar for free
+    pub const MAX_DISCOUNT_BPS: u16 = 5000;
+
+    pub fn is_valid(&self) -> bool {
+        self.threshold_bps <= 10000 && self.discount_bps <= Self::MAX_DISCOUNT_BPS
+    }
+
+    /// Check if a balance meets the holding threshold
+    pub fn qualifies(&self, holder_balance: u64, total_supply: u64) -> bool {
+        self.threshold_bps > 0
+            && total_supply > 0
+            && holder_balance as u128 * 10000 >= self.threshold_bps as u128 * total_supply as u128
+    }
+
+    /// Check if any holder can receive a discount
+    pub fn is_enabled(&self) -> bool {
+        self.threshold_bps > 0 && self.discount_bps > 0
+    }
+
+    /// Check that no holder gets a larger discount than under `current`
+    pub fn is_narrowing_of(&self, current: &HolderPrivilege) -> bool {
+        !self.is_enabled()
+            || (current.is_enabled()
+                && self.discount_bps <= current.discount_bps
+                && self.threshold_bps >= current.threshold_bps)
+    }
+
+    /// Apply the discount to a price
+    pub fn apply(&self, price: u64) -> u64 {
+        let discount = (price as u128 * self.discount_bps as u128) / 10000;
+        price - discount as u64
+    }
+}
+
+/// Maximum operating cost per category, in basis points of gross revenue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OperatingCostCaps {
+    pub maintenance_bps: u16,
+    pub taxes_bps: u16,
+    pub insurance_bps: u16,
+    pub management_fee_bps: u16,
+    pub utilities_bps: u16,
+    pub other_bps: u16,
+}
+
+impl OperatingCostCaps {
+    pub const LEN: usize = 6 * 2;
+
+    /// Check that every cap is at most 100% and the caps sum to at most 100%
+    pub fn is_valid(&self) -> bool {
+        let caps = self.as_array();
+        caps.iter().all(|cap| *cap <= 10000)
+            && caps.iter().map(|cap| *cap as u32).sum::<u32>() <= 10000
+    }
+
+    /// Check that each cost category is within its cap for the given gross revenue
+    pub fn permits(&self, costs: &OperatingCosts, gross_revenue: u64) -> bool {
+        self.as_array()
+            .iter()
+            .zip(costs.as_array().iter())
+            .all(|(cap_bps, cost)| {
+                let limit = (gross_revenue as u128 * *cap_bps as u128) / 10000;
+                *cost as u128 <= limit
+            })
+    }
+
+    /// Check that no cap is higher than in `current`
+    pub fn is_decrease_from(&self, current: &OperatingCostCaps) -> bool {
+        self.as_array()
+            .iter()
+            .zip(current.as_array().iter())
+            .all(|(new_cap, current_cap)| new_cap <= current_cap)
+    }
+
+    fn as_array(&self) -> [u16; 6] {
+        [
+            self.maintenance_bps,
+            self.taxes_bps,
+            self.insurance_bps,
+            self.management_fee_bps,
+            self.utilities_bps,
+            self.other_bps,
+        ]
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum ComplianceStatus {
     Pending,
@@ -103,3 +301,53 @@ impl Default for ComplianceStatus {
         ComplianceStatus::Pending
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn caps(bps: u16) -> OperatingCostCaps {
+        OperatingCostCaps {
+            maintenance_bps: bps,
+            taxes_bps: bps,
+            insurance_bps: bps,
+            management_fee_bps: bps,
+            utilities_bps: bps,
+            other_bps: bps,
+        }
+    }
+
+    #[test]
+    fn cost_caps_decrease_requires_every_category_to_stay_or_fall() {
+        let current = caps(500);
+        assert!(current.is_decrease_from(&current));
+        assert!(caps(400).is_decrease_from(&current));
+        assert!(!caps(600).is_decrease_from(&current));
+
+        // Lowering one cap does not cover raising another
+        let shifted = OperatingCostCaps {
+            maintenance_bps: 0,
+            other_bps: 501,
+            ..current
+        };
+        assert!(!shifted.is_decrease_from(&current));
+    }
+
+    #[test]
+    fn holder_privilege_narrowing() {
+        let current = HolderPrivilege {
+            threshold_bps: 1000,
+            discount_bps: 2000,
+        };
+        let privilege = |threshold_bps, discount_bps| HolderPrivilege {
+            threshold_bps,
+            discount_bps,
+        };
+        let disabled = HolderPrivilege::default();
+        assert!(disabled.is_narrowing_of(&current));
+        assert!(!current.is_narrowing_of(&disabled));
+        assert!(privilege(2000, 1000).is_narrowing_of(&current));
+        assert!(!privilege(500, 2000).is_narrowing_of(&current));
+        assert!(!privilege(1000, 2500).is_narrowing_of(&current));
+    }
+}
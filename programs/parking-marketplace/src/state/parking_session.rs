@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct ParkingSession {
+    /// Parking asset (spot or lot) being used
+    pub asset_account: Pubkey,
+
+    /// Driver who started the session
+    pub driver: Pubkey,
+
+    /// Token mint escrowed and paid in
+    pub payment_mint: Pubkey,
+
+    /// Maximum amount escrowed by the driver (6 decimals)
+    pub max_amount_usdc: u64,
+
+    /// Amount charged at settlement (6 decimals)
+    pub amount_charged_usdc: u64,
+
+    /// Session start timestamp
+    pub started_at: i64,
+
+    /// Session end timestamp (0 while active)
+    pub ended_at: i64,
+
+    /// Session status
+    pub status: SessionStatus,
+
+    /// Asset token account that qualified the driver for the holder discount
+    /// at session start (default key if the driver did not qualify)
+    pub holder_token_account: Pubkey,
+
+    /// Balance of that account at session start
+    pub qualifying_balance: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ParkingSession {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // driver
+        32 + // payment_mint
+        8 + // max_amount_usdc
+        8 + // amount_charged_usdc
+        8 + // started_at
+        8 + // ended_at
+        1 + // status
+        32 + // holder_token_account
+        8 + // qualifying_balance
+        1; // bump
+
+    /// Check if the driver qualified for the holder discount at session start
+    pub fn has_holder_discount(&self) -> bool {
+        self.holder_token_account != Pubkey::default()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStatus {
+    Active,  // Session in progress, payment escrowed
+    Settled, // Charged and refunded at session end
+    Expired, // Timed out without a reading, escrow refunded in full
+}
+
+impl Default for SessionStatus {
+    fn default() -> Self {
+        SessionStatus::Active
+    }
+}
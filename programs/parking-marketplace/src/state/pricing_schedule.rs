@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct PricingSchedule {
+    /// Parking asset (spot or lot) the schedule prices
+    pub asset_account: Pubkey,
+
+    /// Tariffs and multipliers
+    pub rates: PricingRates,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PricingSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        PricingRates::LEN + // rates
+        8 + // updated_at
+        1; // bump
+}
+
+/// Usage tariffs mirroring the `parking_lots` pricing columns.
+/// See [`crate::pricing::quote`] for how a price is computed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PricingRates {
+    /// Tariff applied to a stay
+    pub pricing_type: PricingType,
+
+    /// Price per started hour (6 decimals)
+    pub price_per_hour: u64,
+
+    /// Price per started day, 0 if not offered (6 decimals)
+    pub price_per_day: u64,
+
+    /// Price per started week, 0 if not offered (6 decimals)
+    pub price_per_week: u64,
+
+    /// Price per started 30-day month, 0 if not offered (6 decimals)
+    pub price_per_month: u64,
+
+    /// Local time offset from UTC used for peak hours and weekdays
+    pub utc_offset_seconds: i32,
+
+    /// First peak hour (local, 0-23)
+    pub peak_start_hour: u8,
+
+    /// Hour peak pricing ends (local, 0-23, exclusive)
+    pub peak_end_hour: u8,
+
+    /// Hourly multiplier during peak hours (basis points, 10000 = 1.0x)
+    pub peak_multiplier_bps: u16,
+
+    /// Hourly multiplier per weekday, Sunday first (basis points)
+    pub weekday_multipliers_bps: [u16; 7],
+}
+
+impl PricingRates {
+    pub const LEN: usize = 1 + // pricing_type
+        8 + // price_per_hour
+        8 + // price_per_day
+        8 + // price_per_week
+        8 + // price_per_month
+        4 + // utc_offset_seconds
+        1 + // peak_start_hour
+        1 + // peak_end_hour
+        2 + // peak_multiplier_bps
+        (2 * 7); // weekday_multipliers_bps
+
+    /// Highest multiplier allowed (5.0x)
+    pub const MAX_MULTIPLIER_BPS: u16 = 50000;
+
+    /// Largest UTC offset allowed (14 hours)
+    pub const MAX_UTC_OFFSET_SECONDS: i32 = 14 * 60 * 60;
+
+    /// Validate tariffs and multipliers
+    pub fn is_valid(&self) -> bool {
+        let multiplier_ok = |bps: u16| bps > 0 && bps <= Self::MAX_MULTIPLIER_BPS;
+
+        self.price_per_hour > 0
+            && self.peak_start_hour < 24
+            && self.peak_end_hour < 24
+            && self.utc_offset_seconds.abs() <= Self::MAX_UTC_OFFSET_SECONDS
+            && multiplier_ok(self.peak_multiplier_bps)
+            && self.weekday_multipliers_bps.iter().all(|bps| multiplier_ok(*bps))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PricingType {
+    Hourly,   // Charge per started hour
+    Daily,    // Charge per started day
+    Weekly,   // Charge per started week
+    Monthly,  // Charge per started 30-day month
+    BestRate, // Cheapest applicable tariff
+}
+
+impl Default for PricingType {
+    fn default() -> Self {
+        PricingType::Hourly
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct ProtocolConfig {
+    /// Protocol administrator
+    pub admin: Pubkey,
+
+    /// Token mints accepted for revenue payouts (USDC, EUROC, etc.)
+    pub accepted_payout_mints: Vec<Pubkey>,
+
+    /// Reserve fund wallet that may receive swept unclaimed revenue
+    pub reserve_fund: Pubkey,
+
+    /// Registered auditor/oracle keys that may sign revenue reports
+    pub revenue_oracles: Vec<Pubkey>,
+
+    /// Authority that must approve operator transfers (default key if not required)
+    pub compliance_authority: Pubkey,
+
+    /// Delay before sensitive asset changes take effect
+    pub timelock_delay_seconds: i64,
+
+    /// Emergency guardian allowed to pause instruction groups
+    pub guardian: Pubkey,
+
+    /// Protocol-wide pause flags
+    pub paused: PauseFlags,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const MAX_PAYOUT_MINTS: usize = 5;
+    pub const MAX_REVENUE_ORACLES: usize = 5;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        (4 + (32 * Self::MAX_PAYOUT_MINTS)) + // accepted_payout_mints (vec of pubkeys)
+        32 + // reserve_fund
+        (4 + (32 * Self::MAX_REVENUE_ORACLES)) + // revenue_oracles (vec of pubkeys)
+        32 + // compliance_authority
+        8 + // timelock_delay_seconds
+        32 + // guardian
+        PauseFlags::LEN + // paused
+        1; // bump
+
+    /// Shortest timelock delay for sensitive asset changes (1 day)
+    pub const MIN_TIMELOCK_DELAY_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Longest timelock delay the admin may configure (30 days)
+    pub const MAX_TIMELOCK_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Check if a mint may be used for revenue payouts
+    pub fn accepts_payout_mint(&self, mint: &Pubkey) -> bool {
+        self.accepted_payout_mints.contains(mint)
+    }
+
+    /// Check if a key is a registered revenue oracle
+    pub fn is_revenue_oracle(&self, oracle: &Pubkey) -> bool {
+        self.revenue_oracles.contains(oracle)
+    }
+
+    /// Check if operator transfers need compliance approval
+    pub fn requires_transfer_approval(&self) -> bool {
+        self.compliance_authority != Pubkey::default()
+    }
+
+    /// Key that must approve decommissioning an asset: the compliance
+    /// authority if one is set, otherwise the admin
+    pub fn decommission_approver(&self) -> Pubkey {
+        if self.compliance_authority != Pubkey::default() {
+            self.compliance_authority
+        } else {
+            self.admin
+        }
+    }
+
+    /// Delay applied to sensitive asset changes, never below the minimum
+    pub fn timelock_delay(&self) -> i64 {
+        self.timelock_delay_seconds.max(Self::MIN_TIMELOCK_DELAY_SECONDS)
+    }
+}
+
+/// Instruction groups that can be halted in an emergency
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PauseFlags {
+    /// Listings and purchases of asset tokens and reservations
+    pub trading: bool,
+
+    /// Revenue distribution and claims
+    pub distributions: bool,
+
+    /// Follow-on token issuance
+    pub issuance: bool,
+}
+
+impl PauseFlags {
+    pub const LEN: usize = 1 + 1 + 1;
+}
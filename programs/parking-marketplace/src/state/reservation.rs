@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct SpotCalendar {
+    /// Parking asset (SingleSpot) the calendar belongs to
+    pub asset_account: Pubkey,
+
+    /// Upcoming and in-progress bookings
+    pub bookings: Vec<Booking>,
+
+    /// Operator rules for reselling reservations
+    pub resale_policy: ResalePolicy,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SpotCalendar {
+    pub const MAX_BOOKINGS: usize = 32;
+
+    /// Most upcoming bookings a single driver may hold on one spot
+    pub const MAX_BOOKINGS_PER_DRIVER: usize = 4;
+
+    /// Most upcoming free bookings a single subscription pass may cover on
+    /// one spot, whichever wallet holds it
+    pub const MAX_BOOKINGS_PER_PASS: usize = 4;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        (4 + (Booking::LEN * Self::MAX_BOOKINGS)) + // bookings
+        ResalePolicy::LEN + // resale_policy
+        1; // bump
+
+    /// Drop bookings that have already ended
+    pub fn prune(&mut self, current_time: i64) {
+        self.bookings.retain(|booking| booking.end_time > current_time);
+    }
+
+    /// Check that no booking overlaps the requested interval
+    pub fn is_available(&self, start_time: i64, end_time: i64) -> bool {
+        self.bookings
+            .iter()
+            .all(|booking| !booking.overlaps(start_time, end_time))
+    }
+
+    /// Check if someone other than `driver` has booked the spot at `time`
+    pub fn is_booked_by_other(&self, driver: &Pubkey, time: i64) -> bool {
+        self.bookings.iter().any(|booking| {
+            booking.start_time <= time && time < booking.end_time && booking.driver != *driver
+        })
+    }
+
+    /// Number of bookings held by a driver
+    pub fn bookings_held_by(&self, driver: &Pubkey) -> usize {
+        self.bookings
+            .iter()
+            .filter(|booking| booking.driver == *driver)
+            .count()
+    }
+
+    /// Number of bookings covered by a subscription pass
+    pub fn bookings_covered_by(&self, pass: &Pubkey) -> usize {
+        self.bookings
+            .iter()
+            .filter(|booking| booking.pass == *pass)
+            .count()
+    }
+
+    /// Add a booking (caller must check availability first); `pass` is the
+    /// subscription pass covering it, or the default key if paid
+    pub fn book(&mut self, start_time: i64, end_time: i64, driver: Pubkey, pass: Pubkey) -> bool {
+        if self.bookings.len() >= Self::MAX_BOOKINGS {
+            return false;
+        }
+        self.bookings.push(Booking { start_time, end_time, driver, pass });
+        true
+    }
+
+    /// Free the booking starting at `start_time`
+    pub fn release(&mut self, start_time: i64) {
+        self.bookings.retain(|booking| booking.start_time != start_time);
+    }
+
+    /// Hand the booking starting at `start_time` to a new driver
+    pub fn reassign(&mut self, start_time: i64, driver: Pubkey) {
+        if let Some(booking) = self
+            .bookings
+            .iter_mut()
+            .find(|booking| booking.start_time == start_time)
+        {
+            booking.driver = driver;
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Booking {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub driver: Pubkey,
+    pub pass: Pubkey,
+}
+
+impl Booking {
+    pub const LEN: usize = 8 + 8 + 32 + 32;
+
+    /// Check if this booking overlaps a half-open interval
+    pub fn overlaps(&self, start_time: i64, end_time: i64) -> bool {
+        self.start_time < end_time && start_time < self.end_time
+    }
+}
+
+/// Operator-defined limits on reservation resale
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResalePolicy {
+    /// Maximum resale price relative to the original price (basis points, 0 = no cap)
+    pub price_cap_bps: u16,
+
+    /// Share of the resale price paid to the asset revenue vault (basis points)
+    pub fee_bps: u16,
+}
+
+impl ResalePolicy {
+    pub const LEN: usize = 2 + 2;
+
+    pub fn is_valid(&self) -> bool {
+        self.fee_bps <= 10000
+    }
+
+    /// Check a resale price against the cap
+    pub fn permits(&self, resale_price: u64, original_price: u64) -> bool {
+        self.price_cap_bps == 0
+            || resale_price as u128 * 10000 <= original_price as u128 * self.price_cap_bps as u128
+    }
+
+    /// Fee owed to the asset revenue vault on a resale
+    pub fn fee(&self, resale_price: u64) -> u64 {
+        ((resale_price as u128 * self.fee_bps as u128) / 10000) as u64
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct Reservation {
+    /// Parking asset (SingleSpot) being reserved
+    pub asset_account: Pubkey,
+
+    /// Driver holding the reservation
+    pub driver: Pubkey,
+
+    /// Reservation start timestamp
+    pub start_time: i64,
+
+    /// Reservation end timestamp
+    pub end_time: i64,
+
+    /// Price paid by the current driver (6 decimals)
+    pub price_paid_usdc: u64,
+
+    /// Price paid into the revenue vault at booking (6 decimals); resale
+    /// caps and cancellation refunds are based on it
+    pub original_price_usdc: u64,
+
+    /// Token mint the reservation was paid in
+    pub payment_mint: Pubkey,
+
+    /// Reservation status
+    pub status: ReservationStatus,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Reservation {
+    /// Check if the reservation can still be transferred
+    pub fn is_resellable(&self, current_time: i64) -> bool {
+        self.status == ReservationStatus::Active && self.start_time > current_time
+    }
+
+    /// Check if the driver can still cancel for a refund
+    pub fn is_cancellable(&self, current_time: i64) -> bool {
+        self.status == ReservationStatus::Active && self.start_time > current_time
+    }
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // driver
+        8 + // start_time
+        8 + // end_time
+        8 + // price_paid_usdc
+        8 + // original_price_usdc
+        32 + // payment_mint
+        1 + // status
+        8 + // created_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+impl Default for ReservationStatus {
+    fn default() -> Self {
+        ReservationStatus::Active
+    }
+}
@@ -12,24 +12,52 @@ pub struct RevenueDistribution {
     /// Revenue period end timestamp
     pub period_end: i64,
 
-    /// Total revenue in USDC (6 decimals)
+    /// End of the asset's previous distribution period (0 if first)
+    pub previous_period_end: i64,
+
+    /// Operator declared a gap between the previous period and this one
+    pub gap_declared: bool,
+
+    /// Token mint the distribution pays out in (USDC, EUROC, etc.)
+    pub payout_mint: Pubkey,
+
+    /// Total revenue in payout mint units (6 decimals)
     pub total_revenue_usdc: u64,
 
-    /// Operating costs in USDC (6 decimals)
+    /// Operating costs in payout mint units (6 decimals)
     pub operating_costs_usdc: u64,
 
-    /// Net revenue after costs in USDC (6 decimals)
+    /// Itemized operating costs (sums to operating_costs_usdc)
+    pub cost_breakdown: OperatingCosts,
+
+    /// Holders' share of revenue at distribution time (basis points)
+    pub revenue_share_percentage: u16,
+
+    /// Holders' share of net revenue after costs in payout mint units (6 decimals)
     pub net_revenue_usdc: u64,
 
-    /// Revenue per token in USDC (6 decimals)
+    /// Revenue per token in payout mint units (6 decimals)
     pub revenue_per_token_usdc: u64,
 
-    /// Total amount distributed in USDC (6 decimals)
+    /// Total amount distributed in payout mint units (6 decimals)
     pub total_distributed_usdc: u64,
 
-    /// Total tokens outstanding at distribution time
+    /// Amount moved in from the asset's revenue vault (holder money)
+    pub vault_funded_usdc: u64,
+
+    /// Amount the operator topped up through fund_distribution
+    pub operator_funded_usdc: u64,
+
+    /// Amount paid out to the operator from the revenue vault (costs,
+    /// operator share and rounding remainder); clawed back on failure
+    pub operator_share_usdc: u64,
+
+    /// Total tokens in the holder snapshot (per-token divisor)
     pub total_tokens_outstanding: u64,
 
+    /// Merkle root of holder balances at period end; claims are paid from it
+    pub holder_snapshot_root: [u8; 32],
+
     /// Distribution status
     pub distribution_status: DistributionStatus,
 
@@ -42,6 +70,22 @@ pub struct RevenueDistribution {
     /// Completion timestamp
     pub completed_at: i64,
 
+    /// Length of the claim window, counted from start_distribution
+    pub claim_window_seconds: i64,
+
+    /// Deadline after which unpaid claims no longer block finalization
+    /// (0 until the distribution starts)
+    pub claim_deadline: i64,
+
+    /// Last time the vault balance was reconciled against recorded payouts
+    pub reconciled_at: i64,
+
+    /// Oracle that attested the revenue report (default key if unattested)
+    pub attested_by: Pubkey,
+
+    /// Reason code recorded when the distribution fails
+    pub failure_reason: DistributionFailureReason,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -51,23 +95,45 @@ impl RevenueDistribution {
         32 + // asset_account
         8 + // period_start
         8 + // period_end
+        8 + // previous_period_end
+        1 + // gap_declared
+        32 + // payout_mint
         8 + // total_revenue_usdc
         8 + // operating_costs_usdc
+        OperatingCosts::LEN + // cost_breakdown
+        2 + // revenue_share_percentage
         8 + // net_revenue_usdc
         8 + // revenue_per_token_usdc
         8 + // total_distributed_usdc
+        8 + // vault_funded_usdc
+        8 + // operator_funded_usdc
+        8 + // operator_share_usdc
         8 + // total_tokens_outstanding
+        32 + // holder_snapshot_root
         1 + // distribution_status
         32 + // operator
         8 + // created_at
         8 + // completed_at
+        8 + // claim_window_seconds
+        8 + // claim_deadline
+        8 + // reconciled_at
+        32 + // attested_by
+        1 + // failure_reason
         1; // bump
 
-    /// Calculate net revenue
+    /// Shortest claim window an operator may configure (7 days)
+    pub const MIN_CLAIM_WINDOW_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+    /// Longest claim window an operator may configure (2 years)
+    pub const MAX_CLAIM_WINDOW_SECONDS: i64 = 2 * 365 * 24 * 60 * 60;
+
+    /// Calculate the holders' share of revenue after costs
     pub fn calculate_net_revenue(&mut self) {
-        self.net_revenue_usdc = self
+        let net = self
             .total_revenue_usdc
             .saturating_sub(self.operating_costs_usdc);
+        self.net_revenue_usdc =
+            ((net as u128 * self.revenue_share_percentage as u128) / 10000) as u64;
     }
 
     /// Calculate revenue per token
@@ -79,6 +145,162 @@ impl RevenueDistribution {
         self.revenue_per_token_usdc =
             self.net_revenue_usdc / self.total_tokens_outstanding;
     }
+
+    /// Amount owed to all holders (revenue per token times outstanding tokens)
+    pub fn distributable_amount(&self) -> Option<u64> {
+        self.revenue_per_token_usdc
+            .checked_mul(self.total_tokens_outstanding)
+    }
+
+    /// Check if every claim has been paid out
+    pub fn is_fully_distributed(&self) -> bool {
+        match self.distributable_amount() {
+            Some(amount) => self.total_distributed_usdc >= amount,
+            None => false,
+        }
+    }
+
+    /// Check if the claim window has closed
+    pub fn is_claim_window_closed(&self, current_time: i64) -> bool {
+        self.claim_deadline != 0 && current_time >= self.claim_deadline
+    }
+
+    /// Balance the distribution vault should hold given what was funded
+    /// and what has been paid out through claims
+    pub fn expected_vault_balance(&self) -> Option<u64> {
+        self.vault_funded_usdc
+            .checked_add(self.operator_funded_usdc)?
+            .checked_sub(self.total_distributed_usdc)
+    }
+
+    /// Portion of a distribution vault balance that is holder money from the
+    /// revenue vault. Claims are treated as drawing operator top-ups first.
+    pub fn holder_funds_in(&self, vault_balance: u64) -> u64 {
+        vault_balance.min(self.vault_funded_usdc)
+    }
+
+    /// Check if leftover funds may be swept out of the distribution vault
+    pub fn is_sweepable(&self, current_time: i64) -> bool {
+        match self.distribution_status {
+            DistributionStatus::Failed => true,
+            // Holders always get a full claim window
+            DistributionStatus::Pending => false,
+            _ => self.is_claim_window_closed(current_time),
+        }
+    }
+}
+
+/// Revenue report signed off-chain by a registered oracle.
+/// The Borsh serialization of this struct is the signed message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RevenueReport {
+    /// Parking asset the report covers
+    pub asset_account: Pubkey,
+
+    /// Asset's revenue report nonce; each signed report is usable once
+    pub nonce: u64,
+
+    /// Token mint the distribution pays out in
+    pub payout_mint: Pubkey,
+
+    /// Revenue period start timestamp
+    pub period_start: i64,
+
+    /// Revenue period end timestamp
+    pub period_end: i64,
+
+    /// Gross revenue (6 decimals)
+    pub total_revenue_usdc: u64,
+
+    /// Itemized operating costs (6 decimals)
+    pub operating_costs: OperatingCosts,
+
+    /// Holder balances at period end
+    pub holder_snapshot: HolderSnapshot,
+}
+
+/// Commitment to holder balances at a point in time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HolderSnapshot {
+    /// Merkle root of (holder, balance) leaves
+    pub root: [u8; 32],
+
+    /// Sum of all balances in the tree
+    pub total_supply: u64,
+}
+
+impl HolderSnapshot {
+    pub const LEN: usize = 32 + 8;
+
+    /// Check that the snapshot commits to at least one token
+    pub fn is_valid(&self) -> bool {
+        self.root != [0u8; 32] && self.total_supply > 0
+    }
+}
+
+/// Operating costs deducted from gross revenue, by category (6 decimals)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OperatingCosts {
+    pub maintenance: u64,
+    pub taxes: u64,
+    pub insurance: u64,
+    pub management_fee: u64,
+    pub utilities: u64,
+    pub other: u64,
+}
+
+impl OperatingCosts {
+    pub const LEN: usize = 6 * 8;
+
+    /// Sum of all cost categories
+    pub fn total(&self) -> Option<u64> {
+        self.as_array()
+            .iter()
+            .try_fold(0u64, |sum, cost| sum.checked_add(*cost))
+    }
+
+    pub(crate) fn as_array(&self) -> [u64; 6] {
+        [
+            self.maintenance,
+            self.taxes,
+            self.insurance,
+            self.management_fee,
+            self.utilities,
+            self.other,
+        ]
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct RevenueClaim {
+    /// Revenue distribution the claim belongs to
+    pub distribution_account: Pubkey,
+
+    /// Token holder who claimed
+    pub holder: Pubkey,
+
+    /// Asset tokens held in the distribution's balance snapshot
+    pub token_balance: u64,
+
+    /// Amount paid in the distribution's payout mint (6 decimals)
+    pub amount: u64,
+
+    /// Claim timestamp
+    pub claimed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RevenueClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // distribution_account
+        32 + // holder
+        8 + // token_balance
+        8 + // amount
+        8 + // claimed_at
+        1; // bump
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -94,3 +316,73 @@ impl Default for DistributionStatus {
         DistributionStatus::Pending
     }
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SweepDestination {
+    Operator,    // Return leftovers to the asset operator
+    ReserveFund, // Send leftovers to the protocol reserve fund
+    NextPeriod,  // Roll leftovers into the next pending distribution
+}
+
+impl Default for SweepDestination {
+    fn default() -> Self {
+        SweepDestination::Operator
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DistributionFailureReason {
+    None,
+    InsufficientFunds,
+    IncorrectReport,
+    ComplianceHold,
+    OperatorCancelled,
+    Other,
+}
+
+impl Default for DistributionFailureReason {
+    fn default() -> Self {
+        DistributionFailureReason::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claim_window_runs_only_once_started() {
+        let mut distribution = RevenueDistribution {
+            distribution_status: DistributionStatus::Pending,
+            claim_window_seconds: 100,
+            ..Default::default()
+        };
+        assert!(!distribution.is_claim_window_closed(1_000));
+        assert!(!distribution.is_sweepable(1_000));
+
+        distribution.distribution_status = DistributionStatus::Processing;
+        distribution.claim_deadline = 1_100;
+        assert!(!distribution.is_sweepable(1_099));
+        assert!(distribution.is_sweepable(1_100));
+
+        distribution.distribution_status = DistributionStatus::Failed;
+        assert!(distribution.is_sweepable(0));
+    }
+
+    #[test]
+    fn expected_vault_balance_tracks_funding_and_claims() {
+        let distribution = RevenueDistribution {
+            vault_funded_usdc: 900,
+            operator_funded_usdc: 100,
+            total_distributed_usdc: 250,
+            ..Default::default()
+        };
+        assert_eq!(distribution.expected_vault_balance(), Some(750));
+
+        let overpaid = RevenueDistribution {
+            total_distributed_usdc: 1,
+            ..Default::default()
+        };
+        assert_eq!(overpaid.expected_vault_balance(), None);
+    }
+}
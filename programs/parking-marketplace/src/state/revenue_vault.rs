@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct RevenueVault {
+    /// Parking asset the vault collects revenue for
+    pub asset_account: Pubkey,
+
+    /// Payment token mint collected by this vault (USDC, EUROC, etc.)
+    pub mint: Pubkey,
+
+    /// Token account holding collected revenue
+    pub token_account: Pubkey,
+
+    /// Total payments ever deposited (6 decimals)
+    pub cumulative_inflows: u64,
+
+    /// Payments already accounted for in revenue distributions (6 decimals)
+    pub distributed_inflows: u64,
+
+    /// Timestamp of the most recent settlement or operator deposit
+    pub last_inflow_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RevenueVault {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // mint
+        32 + // token_account
+        8 + // cumulative_inflows
+        8 + // distributed_inflows
+        8 + // last_inflow_at
+        1; // bump
+
+    /// Record a payment deposited into the vault
+    pub fn record_inflow(&mut self, amount: u64, current_time: i64) -> Option<()> {
+        self.cumulative_inflows = self.cumulative_inflows.checked_add(amount)?;
+        self.last_inflow_at = current_time;
+        Some(())
+    }
+
+    /// Record a third-party deposit; it does not move last_inflow_at, so
+    /// outsiders cannot hold back the next distribution period
+    pub fn record_external_inflow(&mut self, amount: u64) -> Option<()> {
+        self.cumulative_inflows = self.cumulative_inflows.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Record a refund paid out of undistributed revenue
+    pub fn record_refund(&mut self, amount: u64) -> Option<()> {
+        if amount > self.undistributed_inflows() {
+            return None;
+        }
+        self.cumulative_inflows -= amount;
+        Some(())
+    }
+
+    /// Payments received since the last distribution
+    pub fn undistributed_inflows(&self) -> u64 {
+        self.cumulative_inflows.saturating_sub(self.distributed_inflows)
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::ParkingAsset;
+
+#[account]
+#[derive(Default)]
+pub struct SubscriptionOffer {
+    /// Parking asset whose revenue vault receives subscription payments
+    pub asset_account: Pubkey,
+
+    /// Reference to parking_lots table in database
+    pub parking_lot_id: u64,
+
+    /// Subscription tier identifier
+    pub tier: u8,
+
+    /// Price per subscription period (6 decimals)
+    pub price_usdc: u64,
+
+    /// Length of one subscription period in seconds
+    pub duration_seconds: i64,
+
+    /// Token mint subscriptions are paid in
+    pub payment_mint: Pubkey,
+
+    /// Offer accepts new purchases and renewals
+    pub is_active: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SubscriptionOffer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        8 + // parking_lot_id
+        1 + // tier
+        8 + // price_usdc
+        8 + // duration_seconds
+        32 + // payment_mint
+        1 + // is_active
+        1; // bump
+
+    /// Shortest subscription period (1 day)
+    pub const MIN_DURATION_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Longest subscription period (1 year)
+    pub const MAX_DURATION_SECONDS: i64 = 366 * 24 * 60 * 60;
+
+    /// Check if passes from this offer may be used on an asset: the offer's own
+    /// asset, or another asset in the same lot run by the offer asset's current
+    /// operator. A lot id alone proves nothing, since anyone can create an asset
+    /// claiming it.
+    pub fn applies_to(
+        &self,
+        asset_key: &Pubkey,
+        asset: &ParkingAsset,
+        offer_asset: Option<&Account<ParkingAsset>>,
+    ) -> bool {
+        if self.asset_account == *asset_key {
+            return true;
+        }
+        offer_asset.is_some_and(|offer_asset| {
+            self.asset_account == offer_asset.key()
+                && offer_asset.institutional_operator == asset.institutional_operator
+                && self.parking_lot_id == asset.parking_lot_id
+        })
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct SubscriptionPass {
+    /// Non-fungible token mint representing the pass
+    pub pass_mint: Pubkey,
+
+    /// Offer the pass was purchased from
+    pub offer: Pubkey,
+
+    /// Reference to parking_lots table in database
+    pub parking_lot_id: u64,
+
+    /// Subscription tier
+    pub tier: u8,
+
+    /// Start of validity
+    pub valid_from: i64,
+
+    /// End of validity
+    pub valid_until: i64,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SubscriptionPass {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pass_mint
+        32 + // offer
+        8 + // parking_lot_id
+        1 + // tier
+        8 + // valid_from
+        8 + // valid_until
+        8 + // created_at
+        1; // bump
+
+    /// Check if a token account held by `holder` contains this pass
+    pub fn is_held_by(&self, token_account: &TokenAccount, holder: &Pubkey) -> bool {
+        token_account.owner == *holder
+            && token_account.mint == self.pass_mint
+            && token_account.amount == 1
+    }
+
+    /// Check if the pass was issued by `offer` and covers the whole interval
+    pub fn covers(&self, offer_key: &Pubkey, start_time: i64, end_time: i64) -> bool {
+        self.offer == *offer_key
+            && self.valid_from <= start_time
+            && end_time <= self.valid_until
+    }
+}
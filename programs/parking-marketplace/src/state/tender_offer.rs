@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+/// Offer to acquire every outstanding token of an asset at a fixed price.
+///
+/// Settlement freezes trading, but classic SPL tokens cannot be moved without
+/// the holder's signature, so the squeeze-out of holders who did not tender
+/// relies on an off-chain legal process; on chain they can only exit through
+/// surrender_tokens at the offer price. Only the bidder's named token account
+/// is excluded from the threshold, so tokens the bidder tenders from a second
+/// wallet still count toward it; that has to be policed off chain.
+#[account]
+#[derive(Default)]
+pub struct TenderOffer {
+    /// Parking asset being acquired
+    pub asset_account: Pubkey,
+
+    /// Acquirer escrowing the funds
+    pub bidder: Pubkey,
+
+    /// Bidder's asset token account (receives acquired tokens)
+    pub bidder_token_account: Pubkey,
+
+    /// Payment token mint (USDC, EUROC, etc.)
+    pub payment_mint: Pubkey,
+
+    /// Fixed price per token (6 decimals)
+    pub price_per_token_usdc: u64,
+
+    /// Outstanding supply the offer is made for
+    pub tokens_sought: u64,
+
+    /// Share of the outstanding supply that must tender (basis points)
+    pub threshold_bps: u16,
+
+    /// Tokens currently tendered into escrow
+    pub tokens_tendered: u64,
+
+    /// Tokens surrendered by late holders after settlement
+    pub tokens_surrendered: u64,
+
+    /// Timestamp after which holders can no longer tender
+    pub expires_at: i64,
+
+    /// Offer status
+    pub status: TenderOfferStatus,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TenderOffer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // bidder
+        32 + // bidder_token_account
+        32 + // payment_mint
+        8 + // price_per_token_usdc
+        8 + // tokens_sought
+        2 + // threshold_bps
+        8 + // tokens_tendered
+        8 + // tokens_surrendered
+        8 + // expires_at
+        1 + // status
+        8 + // created_at
+        1; // bump
+
+    /// Lowest acceptance threshold that allows a squeeze-out (90%)
+    pub const MIN_THRESHOLD_BPS: u16 = 9000;
+
+    /// Longest an offer may stay open (90 days)
+    pub const MAX_DURATION_SECONDS: i64 = 90 * 24 * 60 * 60;
+
+    /// Check if holders may still tender
+    pub fn is_open(&self, current_time: i64) -> bool {
+        self.status == TenderOfferStatus::Open && current_time < self.expires_at
+    }
+
+    /// Check if tendered tokens cross the acceptance threshold
+    pub fn is_threshold_met(&self) -> bool {
+        self.tokens_tendered as u128 * 10000
+            >= self.threshold_bps as u128 * self.tokens_sought as u128
+    }
+
+    /// Payment owed for a number of tokens
+    pub fn payment_for(&self, amount: u64) -> Option<u64> {
+        self.price_per_token_usdc.checked_mul(amount)
+    }
+
+    /// Check that a price values the whole supply at no less than the
+    /// asset's estimated value
+    pub fn meets_price_floor(
+        price_per_token_usdc: u64,
+        total_supply: u64,
+        estimated_value_usdc: u64,
+    ) -> bool {
+        price_per_token_usdc as u128 * total_supply as u128 >= estimated_value_usdc as u128
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct TenderPosition {
+    /// Tender offer the tokens were tendered into
+    pub tender_offer: Pubkey,
+
+    /// Holder who tendered
+    pub holder: Pubkey,
+
+    /// Tokens tendered
+    pub amount: u64,
+
+    /// Tender timestamp
+    pub tendered_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TenderPosition {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // tender_offer
+        32 + // holder
+        8 + // amount
+        8 + // tendered_at
+        1; // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TenderOfferStatus {
+    Open,     // Accepting tenders
+    Settled,  // Threshold met; trading frozen, remainder may surrender
+    Failed,   // Expired below threshold; tenders returned
+}
+
+impl Default for TenderOfferStatus {
+    fn default() -> Self {
+        TenderOfferStatus::Open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(tokens_sought: u64, tokens_tendered: u64) -> TenderOffer {
+        TenderOffer {
+            tokens_sought,
+            tokens_tendered,
+            threshold_bps: TenderOffer::MIN_THRESHOLD_BPS,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn threshold_is_met_at_exactly_the_required_share() {
+        assert!(offer(1000, 900).is_threshold_met());
+        assert!(offer(1000, 1000).is_threshold_met());
+        assert!(!offer(1000, 899).is_threshold_met());
+        assert!(!offer(1000, 0).is_threshold_met());
+    }
+
+    #[test]
+    fn threshold_rounds_against_the_bidder() {
+        // 90% of 7 tokens is 6.3, so 6 tendered tokens fall short
+        assert!(!offer(7, 6).is_threshold_met());
+        assert!(offer(7, 7).is_threshold_met());
+    }
+
+    #[test]
+    fn price_floor_compares_against_the_whole_supply() {
+        assert!(TenderOffer::meets_price_floor(100, 1000, 100_000));
+        assert!(!TenderOffer::meets_price_floor(99, 1000, 100_000));
+        assert!(TenderOffer::meets_price_floor(u64::MAX, u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn threshold_handles_large_supplies() {
+        assert!(offer(u64::MAX, u64::MAX).is_threshold_met());
+        assert!(!offer(u64::MAX, u64::MAX / 10 * 9 - 1).is_threshold_met());
+    }
+}
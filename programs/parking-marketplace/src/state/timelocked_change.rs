@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AssetType, HolderPrivilege, OperatingCostCaps, ParkingAsset, PricingRates, ProtocolConfig,
+};
+
+#[account]
+pub struct TimelockedChange {
+    /// Parking asset the change applies to
+    pub asset_account: Pubkey,
+
+    /// Operator who scheduled the change; only executable while they still
+    /// operate the asset
+    pub operator: Pubkey,
+
+    /// The scheduled change
+    pub change: AssetChange,
+
+    /// Scheduling timestamp
+    pub scheduled_at: i64,
+
+    /// Earliest time the change may be executed
+    pub executable_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TimelockedChange {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // asset_account
+        32 + // operator
+        AssetChange::LEN + // change
+        8 + // scheduled_at
+        8 + // executable_at
+        1; // bump
+
+    /// Check if the delay has elapsed
+    pub fn is_executable(&self, current_time: i64) -> bool {
+        current_time >= self.executable_at
+    }
+}
+
+/// Economically sensitive asset changes that must wait out the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AssetChange {
+    CostCaps(OperatingCostCaps),  // Replace operating cost caps
+    RevenueSharePercentage(u16),  // Change the holders' revenue share
+    MaxSupply(u64),               // Raise the supply cap
+    IssuanceAuthorization(u64),   // Authorize tokens for follow-on issuance
+    RevenueOracle(Pubkey),        // Replace or remove (default key) the revenue oracle
+    HolderPrivilege(HolderPrivilege), // Widen holder usage discounts
+    PricingRates(PricingRates),   // Replace the asset's usage pricing schedule
+}
+
+impl AssetChange {
+    pub const LEN: usize = 1 + PricingRates::LEN; // tag + largest variant (PricingRates)
+
+    /// Check that the change can be applied to the asset
+    pub fn is_valid_for(&self, asset: &ParkingAsset, config: &ProtocolConfig) -> bool {
+        match self {
+            AssetChange::CostCaps(caps) => caps.is_valid(),
+            AssetChange::RevenueSharePercentage(percentage) => *percentage <= 10000,
+            // Bundle tokens must stay fully backed by deposited constituents
+            AssetChange::MaxSupply(_) | AssetChange::IssuanceAuthorization(_)
+                if asset.asset_type == AssetType::ParkingLotBundle =>
+            {
+                false
+            }
+            AssetChange::MaxSupply(max_supply) => *max_supply > asset.max_supply,
+            AssetChange::IssuanceAuthorization(amount) => {
+                *amount > 0
+                    && asset
+                        .authorized_issuance
+                        .checked_add(*amount)
+                        .is_some_and(|total| asset.can_issue(total))
+            }
+            AssetChange::RevenueOracle(oracle) => {
+                *oracle != asset.revenue_oracle
+                    && (*oracle == Pubkey::default() || config.is_revenue_oracle(oracle))
+            }
+            AssetChange::HolderPrivilege(privilege) => {
+                asset.asset_type == AssetType::SingleSpot && privilege.is_valid()
+            }
+            AssetChange::PricingRates(rates) => rates.is_valid(),
+        }
+    }
+
+    /// Apply the change to the asset; pricing changes are written to the
+    /// asset's pricing schedule by execute_asset_change
+    pub fn apply(&self, asset: &mut ParkingAsset) {
+        match self {
+            AssetChange::CostCaps(caps) => asset.cost_caps = *caps,
+            AssetChange::RevenueSharePercentage(percentage) => {
+                asset.revenue_share_percentage = *percentage
+            }
+            AssetChange::MaxSupply(max_supply) => asset.max_supply = *max_supply,
+            AssetChange::IssuanceAuthorization(amount) => {
+                asset.authorized_issuance += *amount
+            }
+            AssetChange::RevenueOracle(oracle) => asset.revenue_oracle = *oracle,
+            AssetChange::HolderPrivilege(privilege) => asset.holder_privilege = *privilege,
+            AssetChange::PricingRates(_) => {}
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, keccak};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::error::MarketplaceError;
+
+/// Size of the Ed25519 instruction header (signature count + padding)
+const ED25519_HEADER_LEN: usize = 2;
+
+/// Size of one Ed25519 signature offsets entry
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Verify that the instruction immediately preceding the current one is an
+/// Ed25519 program instruction checking `expected_signer`'s signature over
+/// `expected_message`. The Ed25519 program itself validates the signature;
+/// this only confirms it was asked to validate the right key and message.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, MarketplaceError::MissingSignedAttestation);
+
+    let ed25519_ix =
+        load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(
+        ed25519_ix.program_id == ed25519_program::ID && ed25519_ix.accounts.is_empty(),
+        MarketplaceError::MissingSignedAttestation
+    );
+
+    let data = &ed25519_ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        MarketplaceError::InvalidSignedAttestation
+    );
+
+    let offsets = &data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_instruction_index = read_u16(6);
+    let message_data_offset = read_u16(8) as usize;
+    let message_data_size = read_u16(10) as usize;
+    let message_instruction_index = read_u16(12);
+
+    // Signature, key and message must all live in the Ed25519 instruction itself
+    require!(
+        signature_instruction_index == u16::MAX
+            && public_key_instruction_index == u16::MAX
+            && message_instruction_index == u16::MAX,
+        MarketplaceError::InvalidSignedAttestation
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(MarketplaceError::InvalidSignedAttestation)?;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(MarketplaceError::InvalidSignedAttestation)?;
+
+    require!(
+        public_key == expected_signer.as_ref(),
+        MarketplaceError::InvalidSignedAttestation
+    );
+    require!(
+        message == expected_message,
+        MarketplaceError::InvalidSignedAttestation
+    );
+
+    Ok(())
+}
+
+/// Longest Merkle proof accepted for a balance snapshot (2^32 holders)
+pub const MAX_SNAPSHOT_PROOF_LEN: usize = 32;
+
+/// Domain separators so a leaf can never be passed off as an interior node
+const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf committing to one holder's token balance in a balance snapshot
+pub fn balance_leaf(holder: &Pubkey, balance: u64) -> [u8; 32] {
+    keccak::hashv(&[MERKLE_LEAF_PREFIX, holder.as_ref(), &balance.to_le_bytes()]).to_bytes()
+}
+
+/// Recompute a Merkle root from `leaf` and its proof. Sibling pairs are
+/// hashed in sorted order, so proofs carry no left/right flags.
+pub fn merkle_root_from_proof(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        keccak::hashv(&[MERKLE_NODE_PREFIX, &left, &right]).to_bytes()
+    })
+}
+
+/// Verify that `holder` held `balance` in the snapshot committed to by `root`
+pub fn verify_balance_snapshot(
+    root: &[u8; 32],
+    holder: &Pubkey,
+    balance: u64,
+    proof: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        proof.len() <= MAX_SNAPSHOT_PROOF_LEN,
+        MarketplaceError::InvalidSnapshotProof
+    );
+    require!(
+        merkle_root_from_proof(balance_leaf(holder, balance), proof) == *root,
+        MarketplaceError::InvalidSnapshotProof
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        merkle_root_from_proof(a, &[b])
+    }
+
+    #[test]
+    fn single_leaf_snapshot_is_its_own_root() {
+        let holder = Pubkey::new_unique();
+        let root = balance_leaf(&holder, 100);
+        assert!(verify_balance_snapshot(&root, &holder, 100, &[]).is_ok());
+        assert!(verify_balance_snapshot(&root, &holder, 101, &[]).is_err());
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf() {
+        let holders: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = holders
+            .iter()
+            .enumerate()
+            .map(|(i, h)| balance_leaf(h, (i as u64 + 1) * 10))
+            .collect();
+        let left = node(leaves[0], leaves[1]);
+        let right = node(leaves[2], leaves[3]);
+        let root = node(left, right);
+
+        assert_eq!(node(left, right), node(right, left));
+        assert!(verify_balance_snapshot(&root, &holders[0], 10, &[leaves[1], right]).is_ok());
+        assert!(verify_balance_snapshot(&root, &holders[3], 40, &[leaves[2], left]).is_ok());
+
+        // Wrong holder or wrong balance fails
+        assert!(verify_balance_snapshot(&root, &holders[1], 10, &[leaves[1], right]).is_err());
+        assert!(verify_balance_snapshot(&root, &holders[0], 20, &[leaves[1], right]).is_err());
+    }
+
+    #[test]
+    fn overlong_proofs_are_rejected() {
+        let holder = Pubkey::new_unique();
+        let proof = vec![[0u8; 32]; MAX_SNAPSHOT_PROOF_LEN + 1];
+        let root = merkle_root_from_proof(balance_leaf(&holder, 1), &proof);
+        assert!(verify_balance_snapshot(&root, &holder, 1, &proof).is_err());
+    }
+}